@@ -4,6 +4,7 @@
 //! Do not manually edit these files.
 //! These files may be overwritten by the codegen system at any time.
 pub mod counter;
+pub mod erc20;
 pub mod fund;
 pub mod load;
 pub mod multicall_3;
@@ -0,0 +1,170 @@
+//! Runs the hand-assembled ERC-20 bytecode in revm, as it has no Solidity source to test instead.
+
+use bindings::erc20::{
+    AllowanceCall, AllowanceReturn, ApproveCall, BalanceOfCall, BalanceOfReturn, DecimalsCall,
+    DecimalsReturn, MintCall, NameCall, NameReturn, OwnerCall, OwnerReturn, SymbolCall,
+    SymbolReturn, TotalSupplyCall, TotalSupplyReturn, TransferCall, TransferFromCall,
+    ERC20_BYTECODE,
+};
+use ethers::{
+    abi::{AbiDecode, AbiEncode},
+    types::{Address, U256},
+};
+use revm::{
+    primitives::{ExecutionResult, Output, TransactTo},
+    Evm, InMemoryDB,
+};
+
+const OWNER: Address = Address::repeat_byte(1);
+const ALICE: Address = Address::repeat_byte(2);
+const BOB: Address = Address::repeat_byte(3);
+
+struct Token {
+    evm: Evm<'static, (), InMemoryDB>,
+    address: Address,
+}
+
+impl Token {
+    /// Deploys the token from `OWNER`
+    fn deploy() -> Self {
+        let mut evm = Evm::builder().with_db(InMemoryDB::default()).build();
+        let result = transact(&mut evm, OWNER, TransactTo::create(), ERC20_BYTECODE.to_vec());
+        let address = match result {
+            ExecutionResult::Success { output: Output::Create(_, Some(address)), .. } => {
+                Address::from(address.into_array())
+            }
+            result => panic!("deployment failed: {result:?}"),
+        };
+        Self { evm, address }
+    }
+
+    /// Sends `call` from `caller` & returns its output, or `None` if it reverted
+    fn send(&mut self, caller: Address, call: impl AbiEncode) -> Option<Vec<u8>> {
+        let to = TransactTo::Call(self.address.0.into());
+        match transact(&mut self.evm, caller, to, call.encode()) {
+            ExecutionResult::Success { output, .. } => Some(output.into_data().to_vec()),
+            ExecutionResult::Revert { .. } => None,
+            result => panic!("call halted: {result:?}"),
+        }
+    }
+
+    fn call<R: AbiDecode>(&mut self, call: impl AbiEncode) -> R {
+        let output = self.send(ALICE, call).expect("view call reverted");
+        R::decode(output).expect("invalid output")
+    }
+
+    fn balance_of(&mut self, account: Address) -> U256 {
+        self.call::<BalanceOfReturn>(BalanceOfCall { account }).0
+    }
+
+    fn allowance(&mut self, owner: Address, spender: Address) -> U256 {
+        self.call::<AllowanceReturn>(AllowanceCall { owner, spender }).0
+    }
+
+    fn total_supply(&mut self) -> U256 {
+        self.call::<TotalSupplyReturn>(TotalSupplyCall).0
+    }
+
+    fn mint(&mut self, caller: Address, to: Address, amount: u64) -> bool {
+        self.send(caller, MintCall { to, amount: amount.into() }).is_some()
+    }
+}
+
+fn transact(
+    evm: &mut Evm<'static, (), InMemoryDB>,
+    caller: Address,
+    to: TransactTo,
+    data: Vec<u8>,
+) -> ExecutionResult {
+    let tx = evm.tx_mut();
+    tx.caller = caller.0.into();
+    tx.transact_to = to;
+    tx.data = data.into();
+    tx.gas_limit = 1_000_000;
+    evm.transact_commit().expect("invalid tx")
+}
+
+#[test]
+fn erc20_metadata() {
+    let mut token = Token::deploy();
+    assert_eq!(token.call::<NameReturn>(NameCall).0, "DTP Token");
+    assert_eq!(token.call::<SymbolReturn>(SymbolCall).0, "DTP");
+    assert_eq!(token.call::<DecimalsReturn>(DecimalsCall).0, 18);
+    assert_eq!(token.call::<OwnerReturn>(OwnerCall).0, OWNER);
+    assert_eq!(token.total_supply(), U256::zero());
+}
+
+#[test]
+fn erc20_mint() {
+    let mut token = Token::deploy();
+    assert!(token.mint(OWNER, ALICE, 100));
+    assert!(token.mint(OWNER, BOB, 50));
+    assert_eq!(token.balance_of(ALICE), 100.into());
+    assert_eq!(token.balance_of(BOB), 50.into());
+    assert_eq!(token.total_supply(), 150.into());
+
+    // only the owner may mint
+    assert!(!token.mint(ALICE, ALICE, 100));
+    assert_eq!(token.balance_of(ALICE), 100.into());
+    assert_eq!(token.total_supply(), 150.into());
+
+    // the total supply can't overflow
+    let max = MintCall { to: BOB, amount: U256::MAX };
+    assert!(token.send(OWNER, max).is_none());
+    assert_eq!(token.total_supply(), 150.into());
+}
+
+#[test]
+fn erc20_transfer() {
+    let mut token = Token::deploy();
+    assert!(token.mint(OWNER, ALICE, 100));
+
+    let transfer = TransferCall { to: BOB, amount: 30.into() };
+    let output = token.send(ALICE, transfer).expect("transfer reverted");
+    assert!(bool::decode(output).unwrap());
+    assert_eq!(token.balance_of(ALICE), 70.into());
+    assert_eq!(token.balance_of(BOB), 30.into());
+
+    // beyond the balance
+    assert!(token.send(ALICE, TransferCall { to: BOB, amount: 71.into() }).is_none());
+    assert_eq!(token.balance_of(ALICE), 70.into());
+    assert_eq!(token.balance_of(BOB), 30.into());
+    assert_eq!(token.total_supply(), 100.into());
+}
+
+#[test]
+fn erc20_transfer_from() {
+    let mut token = Token::deploy();
+    assert!(token.mint(OWNER, ALICE, 100));
+
+    let approve = ApproveCall { spender: BOB, amount: 40.into() };
+    let output = token.send(ALICE, approve).expect("approve reverted");
+    assert!(bool::decode(output).unwrap());
+    assert_eq!(token.allowance(ALICE, BOB), 40.into());
+    assert_eq!(token.allowance(BOB, ALICE), U256::zero());
+
+    // deducts the allowance
+    let transfer_from = TransferFromCall { from: ALICE, to: OWNER, amount: 25.into() };
+    let output = token.send(BOB, transfer_from).expect("transferFrom reverted");
+    assert!(bool::decode(output).unwrap());
+    assert_eq!(token.balance_of(ALICE), 75.into());
+    assert_eq!(token.balance_of(OWNER), 25.into());
+    assert_eq!(token.allowance(ALICE, BOB), 15.into());
+
+    // beyond the allowance
+    let transfer_from = TransferFromCall { from: ALICE, to: BOB, amount: 16.into() };
+    assert!(token.send(BOB, transfer_from).is_none());
+    assert_eq!(token.balance_of(ALICE), 75.into());
+    assert_eq!(token.allowance(ALICE, BOB), 15.into());
+
+    // an unlimited allowance isn't deducted, but the balance still bounds the amount
+    assert!(token.send(ALICE, ApproveCall { spender: BOB, amount: U256::MAX }).is_some());
+    let transfer_from = TransferFromCall { from: ALICE, to: BOB, amount: 75.into() };
+    assert!(token.send(BOB, transfer_from).is_some());
+    assert_eq!(token.balance_of(ALICE), U256::zero());
+    assert_eq!(token.balance_of(BOB), 75.into());
+    assert_eq!(token.allowance(ALICE, BOB), U256::MAX);
+    let transfer_from = TransferFromCall { from: ALICE, to: BOB, amount: 1.into() };
+    assert!(token.send(BOB, transfer_from).is_none());
+    assert_eq!(token.total_supply(), 100.into());
+}
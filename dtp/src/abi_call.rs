@@ -0,0 +1,280 @@
+use crate::{
+    calldata::calldata_send,
+    transport::Client,
+    utils::{run_tracked, Senders},
+};
+use ethers::{
+    abi::{
+        token::{LenientTokenizer, Tokenizer},
+        Abi, Function, ParamType, Token,
+    },
+    core::rand::{thread_rng, Rng},
+    prelude::*,
+};
+use std::{path::Path, str::FromStr, sync::Arc, time::Duration};
+
+/// Generator of an argument of the ABI txs, i.e. of an input of the called function.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ArgGen {
+    /// `const:<value>`: the same value for all the txs, in the input's ABI type (e.g. `42`,
+    /// `0xab...`, `[1,2]`, `hello`)
+    Const(String),
+    /// `random:<min>..<max>`: a random integer within [min, max)
+    Random(U256, U256),
+    /// `account`: the index of the tx's sender among the new accounts
+    Account,
+    /// `seq[:<start>]`: the index of the tx among all the txs, counted from `start`
+    Seq(U256),
+}
+
+/// Implement `FromStr` trait for ArgGen
+impl FromStr for ArgGen {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_int = |value: &str| {
+            U256::from_dec_str(value.trim())
+                .map_err(|e| format!("\'{}\' is not a valid integer: {}", value, e))
+        };
+        let (kind, value) = s.split_once(':').unwrap_or((s, ""));
+        match kind.to_lowercase().as_str() {
+            "const" => Ok(ArgGen::Const(value.to_string())),
+            "random" => {
+                let (min, max) = value
+                    .split_once("..")
+                    .ok_or_else(|| format!("\'{}\' is not a valid `<min>..<max>` range", value))?;
+                let (min, max) = (parse_int(min)?, parse_int(max)?);
+                if min >= max {
+                    return Err(format!("Range \'{}\' is empty", value));
+                }
+                Ok(ArgGen::Random(min, max))
+            }
+            "account" => Ok(ArgGen::Account),
+            "seq" if value.is_empty() => Ok(ArgGen::Seq(U256::zero())),
+            "seq" => Ok(ArgGen::Seq(parse_int(value)?)),
+            _ => Err(format!("\'{}\' is not a valid ArgGen", s)),
+        }
+    }
+}
+
+impl ArgGen {
+    /// Generate the argument of type `kind` for the `tx`-th tx, sent by the `account`-th account.
+    /// The integers out of range of `kind` (e.g. beyond `type(int8).max`) are rejected, and so is
+    /// a `random` range partly out of it, whatever the value drawn.
+    fn generate(&self, kind: &ParamType, tx: usize, account: usize) -> eyre::Result<Token> {
+        let (num, largest) = match self {
+            ArgGen::Const(value) => return Ok(LenientTokenizer::tokenize(kind, value)?),
+            ArgGen::Random(min, max) => {
+                let random = U256::from_big_endian(&thread_rng().gen::<[u8; 32]>());
+                (*min + random % (*max - *min), *max - 1)
+            }
+            ArgGen::Account => (U256::from(account), U256::from(account)),
+            ArgGen::Seq(start) => {
+                let num = start
+                    .checked_add(U256::from(tx))
+                    .ok_or_else(|| eyre::eyre!("{:?} overflows a uint256 at tx {}", self, tx))?;
+                (num, num)
+            }
+        };
+        // no. of bits of the largest non-negative value of `kind`
+        let bits = match kind {
+            ParamType::Uint(bits) => *bits,
+            ParamType::Int(bits) => *bits - 1,
+            _ => eyre::bail!("{:?} only generates integers, not {}", self, kind),
+        };
+        eyre::ensure!(
+            bits >= 256 || largest >> bits == U256::zero(),
+            "{:?} generates {} at tx {}, out of range of {}",
+            self,
+            largest,
+            tx,
+            kind
+        );
+        match kind {
+            ParamType::Uint(_) => Ok(Token::Uint(num)),
+            _ => Ok(Token::Int(num)),
+        }
+    }
+}
+
+/// A call of any contract function, encoded from its ABI for each tx.
+#[derive(Debug)]
+pub(crate) struct AbiCall {
+    function: Function,
+    args: Vec<ArgGen>,
+}
+
+impl AbiCall {
+    /// Look up the function `signature` (e.g. `setNumber(uint256)`, or just `setNumber` if not
+    /// overloaded) in the ABI JSON file (either an ABI or an artifact w/ an `abi` field), and
+    /// check that `args` can generate its inputs.
+    pub(crate) fn load(abi_file: &Path, signature: &str, args: Vec<ArgGen>) -> eyre::Result<Self> {
+        let json: serde_json::Value = serde_json::from_reader(std::fs::File::open(abi_file)?)?;
+        let abi: Abi = serde_json::from_value(json.get("abi").cloned().unwrap_or(json))?;
+
+        let signature: String = signature.chars().filter(|c| !c.is_whitespace()).collect();
+        let mut functions = abi.functions().filter(|function| {
+            let inputs: Vec<String> =
+                function.inputs.iter().map(|input| input.kind.to_string()).collect();
+            function.name == signature
+                || format!("{}({})", function.name, inputs.join(",")) == signature
+        });
+        let function = functions
+            .next()
+            .ok_or_else(|| eyre::eyre!("No function \'{}\' in {:?}", signature, abi_file))?
+            .clone();
+        eyre::ensure!(
+            functions.next().is_none(),
+            "Function \'{}\' is overloaded, give its full signature",
+            signature
+        );
+
+        eyre::ensure!(
+            args.len() == function.inputs.len(),
+            "Function \'{}\' takes {} args, but {} generators are given",
+            signature,
+            function.inputs.len(),
+            args.len()
+        );
+        let abi_call = Self { function, args };
+        abi_call.encode(0, 0)?;
+
+        Ok(abi_call)
+    }
+
+    /// Function signature, w/o its outputs.
+    pub(crate) fn name(&self) -> String {
+        let inputs: Vec<String> =
+            self.function.inputs.iter().map(|input| input.kind.to_string()).collect();
+        format!("{}({})", self.function.name, inputs.join(","))
+    }
+
+    /// Calldata of the `tx`-th tx, sent by the `account`-th account.
+    fn encode(&self, tx: usize, account: usize) -> eyre::Result<Bytes> {
+        let tokens = self
+            .function
+            .inputs
+            .iter()
+            .zip(self.args.iter())
+            .map(|(input, arg)| arg.generate(&input.kind, tx, account))
+            .collect::<eyre::Result<Vec<Token>>>()?;
+
+        Ok(self.function.encode_input(&tokens)?.into())
+    }
+}
+
+/// As ABI transaction type, each new account sends txs calling `abi_call` on the contract at
+/// `contract_address`, w/ the arguments generated per tx, in batches of `MAX_BATCH_SIZE`. The gas
+/// of each tx is estimated.
+///
+/// The reverted txs & the avg. gas used per tx are reported.
+pub(crate) async fn multicall_abi_txs(
+    client: Arc<Client>,
+    contract_address: Address,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    abi_call: &AbiCall,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let label = abi_call.name();
+    let num_signers = senders.signers.len();
+
+    let txs: Vec<usize> = (0..senders.num_txs()).collect();
+    let data = txs
+        .iter()
+        .map(|&tx| abi_call.encode(tx, tx % num_signers))
+        .collect::<eyre::Result<Vec<Bytes>>>()?;
+    let (_, report) = run_tracked(
+        client.clone(),
+        &senders,
+        &txs,
+        max_batch_size,
+        inclusion_timeout,
+        &label,
+        |tx, signer, nonce| {
+            calldata_send(
+                client.clone(),
+                signer,
+                senders.chain_id,
+                &senders.fees,
+                contract_address,
+                data[tx].clone(),
+                None,
+                nonce,
+            )
+        },
+    )
+    .await?;
+    report.print_receipts(&label);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arg_gen_from_str() {
+        let cases = [
+            ("const:42", ArgGen::Const("42".to_string())),
+            // only the 1st `:` splits the kind from the value
+            ("const:a:b", ArgGen::Const("a:b".to_string())),
+            ("const", ArgGen::Const(String::new())),
+            ("random:1..10", ArgGen::Random(1.into(), 10.into())),
+            ("RANDOM: 0 .. 1", ArgGen::Random(0.into(), 1.into())),
+            ("account", ArgGen::Account),
+            ("seq", ArgGen::Seq(0.into())),
+            ("seq:", ArgGen::Seq(0.into())),
+            ("seq:5", ArgGen::Seq(5.into())),
+        ];
+        for (s, arg_gen) in cases {
+            assert_eq!(s.parse::<ArgGen>(), Ok(arg_gen), "{}", s);
+        }
+
+        for s in ["random:5..5", "random:6..5", "random:1", "random:a..2", "seq:-1", "nope", ""] {
+            assert!(s.parse::<ArgGen>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn arg_gen_generate() {
+        let uint = ParamType::Uint(256);
+        assert_eq!(ArgGen::Seq(5.into()).generate(&uint, 3, 1).unwrap(), Token::Uint(8.into()));
+        assert_eq!(ArgGen::Account.generate(&uint, 3, 1).unwrap(), Token::Uint(1.into()));
+        assert_eq!(
+            ArgGen::Const("42".to_string()).generate(&uint, 3, 1).unwrap(),
+            Token::Uint(42.into())
+        );
+        for _ in 0..100 {
+            match ArgGen::Random(10.into(), 13.into()).generate(&uint, 0, 0).unwrap() {
+                Token::Uint(num) => assert!(num >= 10.into() && num < 13.into()),
+                token => panic!("{:?} is no uint", token),
+            }
+        }
+        assert!(ArgGen::Account.generate(&ParamType::Address, 0, 0).is_err());
+    }
+
+    #[test]
+    fn arg_gen_generate_out_of_range() {
+        let (uint8, int8) = (ParamType::Uint(8), ParamType::Int(8));
+        assert_eq!(
+            ArgGen::Seq(250.into()).generate(&uint8, 5, 0).unwrap(),
+            Token::Uint(255.into())
+        );
+        assert!(ArgGen::Seq(250.into()).generate(&uint8, 6, 0).is_err());
+        // the largest int8 is 127
+        assert_eq!(ArgGen::Account.generate(&int8, 0, 127).unwrap(), Token::Int(127.into()));
+        assert!(ArgGen::Account.generate(&int8, 0, 128).is_err());
+        // the whole range is checked, not only the value drawn
+        assert!(ArgGen::Random(0.into(), 256.into()).generate(&uint8, 0, 0).is_ok());
+        assert!(ArgGen::Random(0.into(), 257.into()).generate(&uint8, 0, 0).is_err());
+        assert!(ArgGen::Random(0.into(), 129.into()).generate(&int8, 0, 0).is_err());
+        // the largest int256 is 2^255 - 1
+        let int256 = ParamType::Int(256);
+        assert!(ArgGen::Seq(U256::MAX >> 1).generate(&int256, 0, 0).is_ok());
+        assert!(ArgGen::Seq(U256::MAX >> 1).generate(&int256, 1, 0).is_err());
+        // a seq overflowing a uint256 errors out
+        assert!(ArgGen::Seq(U256::MAX).generate(&ParamType::Uint(256), 1, 0).is_err());
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{
+    fees::{signer_middleware, TxFees},
+    presign::PresignedCall,
+    scheduler::get_block_gas_limit,
+    transport::Client,
+    utils::{run_tracked, Senders},
+};
+use ethers::{
+    core::k256::ecdsa::SigningKey,
+    prelude::*,
+    signers::Wallet,
+    types::transaction::{
+        eip2718::TypedTransaction,
+        eip2930::{AccessList, AccessListItem, Eip2930TransactionRequest},
+    },
+};
+use std::{collections::HashMap, str::FromStr, sync::Arc, time::Duration};
+
+/// Source of the access list (EIP-2930) attached to the txs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AccessListSource {
+    /// No access list
+    None,
+    /// Generated by the node via `eth_createAccessList`
+    Rpc,
+    /// Built from the storage slots known to be touched by the call
+    Static,
+}
+
+/// Implement `FromStr` trait for AccessListSource
+impl FromStr for AccessListSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(AccessListSource::None),
+            "rpc" => Ok(AccessListSource::Rpc),
+            "static" => Ok(AccessListSource::Static),
+            _ => Err(format!("\'{}\' is not a valid AccessListSource", s)),
+        }
+    }
+}
+
+/// EIP-2930 tx making `call` on top of `tx`, w/ `access_list`.
+fn access_list_tx(
+    tx: TransactionRequest,
+    call: &PresignedCall,
+    access_list: AccessList,
+) -> Eip2930TransactionRequest {
+    Eip2930TransactionRequest::new(tx.to(call.to).data(call.data.clone()), access_list)
+}
+
+/// Build the access list of `call` sent by `from`, from the `source`.
+async fn build_access_list(
+    client: Arc<Client>,
+    source: AccessListSource,
+    call: &PresignedCall,
+    from: Address,
+) -> eyre::Result<AccessList> {
+    let access_list = match source {
+        AccessListSource::None => AccessList::default(),
+        AccessListSource::Rpc => {
+            let tx: TypedTransaction =
+                access_list_tx(TransactionRequest::new().from(from), call, AccessList::default())
+                    .into();
+            client.create_access_list(&tx, None).await?.access_list
+        }
+        AccessListSource::Static if call.slots.is_empty() => AccessList::default(),
+        AccessListSource::Static => {
+            AccessList(vec![AccessListItem { address: call.to, storage_keys: call.slots.clone() }])
+        }
+    };
+
+    Ok(access_list)
+}
+
+/// send a tx making `call` w/ the given `access_list` (possibly empty) & `nonce` w/o waiting for
+/// its receipt
+/// NOTE: signer needed as it incurs gas fees.
+async fn access_list_call_send(
+    client: Arc<Client>,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    call: &PresignedCall,
+    access_list: AccessList,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client, signer, chain_id, fees);
+
+    // the access list is kept when converted into the tx envelope
+    let tx = access_list_tx(TransactionRequest::new().nonce(nonce), call, access_list);
+    let tx_hash = client_middleware.send_transaction(tx, None).await?.tx_hash();
+
+    Ok(tx_hash)
+}
+
+/// With an access list `source`, the LIGHT/HEAVY/COMPUTE txs of the new accounts make `call` w/
+/// & w/o its access list in turn (every other tx), in batches of `MAX_BATCH_SIZE`, so that both
+/// run against the same chain state. The access list is built once, and the gas of each tx is
+/// estimated w/ its list (if any).
+///
+/// The gas used w/ & w/o the list is compared, and so the no. of txs fitting in a block.
+pub(crate) async fn multicall_access_list_txs(
+    client: Arc<Client>,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    call: PresignedCall,
+    source: AccessListSource,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let from = senders.signer(0).address();
+    let access_list = build_access_list(client.clone(), source, &call, from).await?;
+    let num_keys: usize = access_list.0.iter().map(|item| item.storage_keys.len()).sum();
+    let estimate = |access_list: AccessList| {
+        let tx: TypedTransaction =
+            access_list_tx(TransactionRequest::new().from(from), &call, access_list).into();
+        let client = client.clone();
+        async move { client.estimate_gas(&tx, None).await }
+    };
+    println!(
+        "\'{}\' access list ({:?}): {} addresses, {} storage keys. Estimated gas: {} w/o it, {} w/ it",
+        call.name,
+        source,
+        access_list.0.len(),
+        num_keys,
+        estimate(AccessList::default()).await?,
+        estimate(access_list.clone()).await?
+    );
+
+    let txs: Vec<usize> = (0..senders.num_txs()).collect();
+    let (sent, report) = run_tracked(
+        client.clone(),
+        &senders,
+        &txs,
+        max_batch_size,
+        inclusion_timeout,
+        &call.name,
+        |tx, signer, nonce| {
+            let access_list = if tx % 2 == 0 { access_list.clone() } else { AccessList::default() };
+            access_list_call_send(
+                client.clone(),
+                signer,
+                senders.chain_id,
+                &senders.fees,
+                &call,
+                access_list,
+                nonce,
+            )
+        },
+    )
+    .await?;
+    let sent: HashMap<TxHash, bool> =
+        sent.into_iter().map(|(tx, tx_hash)| (tx_hash, tx % 2 == 0)).collect();
+
+    // the receipts of the included txs, per list
+    let mut num_sent = [0_usize; 2];
+    for with_list in sent.values() {
+        num_sent[*with_list as usize] += 1;
+    }
+    let stats = report.receipt_stats_by(|tx_hash| sent[tx_hash]);
+
+    let block_gas_limit = get_block_gas_limit(client.clone()).await?;
+    println!(
+        "=====\n{:<12} {:>8} {:>9} {:>9} {:>12} {:>15}",
+        "access list", "sent", "included", "reverted", "avg. gas", "fit per block"
+    );
+    let mut avg_gas = [U256::zero(); 2];
+    for (with_list, num_sent) in num_sent.iter().enumerate() {
+        let list_stats = stats.get(&(with_list == 1)).copied().unwrap_or_default();
+        avg_gas[with_list] = list_stats.avg_gas_used();
+        println!(
+            "{:<12} {:>8} {:>9} {:>9} {:>12} {:>15}",
+            if with_list == 1 { "w/" } else { "w/o" },
+            num_sent,
+            list_stats.included,
+            list_stats.reverted,
+            avg_gas[with_list],
+            block_gas_limit / avg_gas[with_list].max(U256::one())
+        );
+    }
+    if avg_gas.iter().all(|gas| !gas.is_zero()) {
+        let saved = avg_gas[0].as_u64() as i64 - avg_gas[1].as_u64() as i64;
+        println!(
+            "The access list saves {} gas per \'{}\' tx ({:.2} %) in a block gas limit of {}",
+            saved,
+            call.name,
+            saved as f64 * 100.0 / avg_gas[0].as_u64() as f64,
+            block_gas_limit
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,238 @@
+use crate::{
+    contracts::{multicall_aggregate3_estimate_gas, multicall_aggregate3_send},
+    presign::PresignedCall,
+    scheduler::get_block_gas_limit,
+    transport::Client,
+    utils::{track_inclusion, Senders},
+};
+use bindings::multicall_3::{Aggregate3Call, Aggregate3Return, Call3, Result as CallResult};
+use ethers::{
+    abi::{AbiDecode, AbiEncode},
+    prelude::*,
+    types::transaction::eip2718::TypedTransaction,
+};
+use std::{sync::Arc, time::Duration};
+
+/// Margin (in %) added over the estimated gas of each `aggregate3` tx.
+const AGGREGATE_GAS_MARGIN_PERCENT: u64 = 10;
+
+/// No. of calls packed in the sample `aggregate3` call, from which the gas per call is estimated.
+const AGGREGATE_GAS_SAMPLE_LEN: usize = 10;
+
+/// Single-sender approach: instead of each new account sending its own txs, the first new account
+/// packs all their calls (`TXS_PER_ACCOUNT` x no. of accounts) into Multicall3 `aggregate3` txs,
+/// each sized to the block gas limit. The calls are allowed to fail individually.
+///
+/// The txs are sent back-to-back with sequential nonces into an `InclusionTracker`. Then, the
+/// `Result[]` returned by each included tx is decoded from its call trace (via
+/// `debug_traceTransaction`) to report the success of each call, so that one big tx can be compared
+/// against many small ones. W/o tracing support, the tx's calls are replayed via `eth_call` on top
+/// of the block's parent instead.
+pub(crate) async fn aggregate_txs(
+    client: Arc<Client>,
+    multicall_address: Address,
+    senders: Arc<Senders>,
+    call: PresignedCall,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let signer = senders.signer(0).to_owned();
+    let chain_id = senders.chain_id;
+    let fees = &senders.fees;
+    let calls: Vec<Call3> = (0..senders.num_txs())
+        .map(|_| Call3 { target: call.to, allow_failure: true, call_data: call.data.clone() })
+        .collect();
+
+    // estimate the gas per call (and the base gas of a tx) from 1 & a few calls
+    let sample_len = calls.len().min(AGGREGATE_GAS_SAMPLE_LEN);
+    let gas_one = multicall_aggregate3_estimate_gas(
+        client.clone(),
+        multicall_address,
+        signer.clone(),
+        chain_id,
+        fees,
+        calls[..1].to_vec(),
+    )
+    .await?;
+    let gas_per_call = if sample_len > 1 {
+        let gas_sample = multicall_aggregate3_estimate_gas(
+            client.clone(),
+            multicall_address,
+            signer.clone(),
+            chain_id,
+            fees,
+            calls[..sample_len].to_vec(),
+        )
+        .await?;
+        gas_sample.saturating_sub(gas_one) / (sample_len - 1)
+    } else {
+        gas_one
+    };
+    let base_gas = gas_one.saturating_sub(gas_per_call);
+    // gas limit of a tx w/ `len` calls, with a margin over the estimate
+    let tx_gas = |len: usize| {
+        (base_gas + gas_per_call * len) * (100 + AGGREGATE_GAS_MARGIN_PERCENT) / 100_u64
+    };
+
+    // max. no. of calls per tx, within the block gas limit
+    let block_gas_limit = get_block_gas_limit(client.clone()).await?;
+    let max_calls = (block_gas_limit * 100_u64 / (100 + AGGREGATE_GAS_MARGIN_PERCENT))
+        .saturating_sub(base_gas)
+        / gas_per_call.max(U256::one());
+    eyre::ensure!(
+        !max_calls.is_zero(),
+        "A single \'{}\' call doesn't fit in the block gas limit of {}",
+        call.name,
+        block_gas_limit
+    );
+    let chunk_size = max_calls.min(U256::from(calls.len())).as_usize();
+    println!(
+        "Packing {} \'{}\' calls into {} \'Multicall3::aggregate3\' tx(s) of up to {} calls, sent by {:?} (~{} gas per call, {} block gas limit)...",
+        calls.len(),
+        call.name,
+        calls.len().div_ceil(chunk_size),
+        chunk_size,
+        signer.address(),
+        gas_per_call,
+        block_gas_limit
+    );
+
+    // Send the txs back-to-back with sequential nonces, w/o waiting for each receipt
+    let label = "Multicall3::aggregate3()";
+    let (txs, report) =
+        track_inclusion(client.clone(), inclusion_timeout, label, async |tracker| {
+            let mut txs = Vec::with_capacity(calls.len().div_ceil(chunk_size));
+            for chunk in calls.chunks(chunk_size) {
+                let gas = tx_gas(chunk.len());
+                let tx_hash = senders
+                    .nonces
+                    .send(client.as_ref(), signer.address(), |nonce| {
+                        multicall_aggregate3_send(
+                            client.clone(),
+                            multicall_address,
+                            signer.clone(),
+                            chain_id,
+                            fees,
+                            chunk.to_vec(),
+                            gas,
+                            nonce,
+                        )
+                    })
+                    .await?;
+                tracker.track(tx_hash);
+                txs.push((tx_hash, chunk));
+            }
+            Ok(txs)
+        })
+        .await?;
+
+    // decode the per-call success of each included tx
+    let (mut succeeded, mut failed, mut undecoded) = (0_usize, 0_usize, 0_usize);
+    let mut gas_used = U256::zero();
+    for (tx_hash, chunk) in txs {
+        let num_calls = chunk.len();
+        let (inclusion, tx_receipt) =
+            match (report.included.get(&tx_hash), report.receipts.get(&tx_hash)) {
+                (Some(inclusion), Some(tx_receipt)) => (inclusion, tx_receipt),
+                _ => continue,
+            };
+        let tx_gas_used = tx_receipt.gas_used.unwrap_or_default();
+        gas_used += tx_gas_used;
+        if tx_receipt.status != Some(U64::one()) {
+            println!(
+                "\'aggregate3\' tx {:?} (block #{}, index {}) reverted, so did its {} calls, gas used: {}",
+                tx_hash, inclusion.block_number, inclusion.index, num_calls, tx_gas_used
+            );
+            failed += num_calls;
+            continue;
+        }
+
+        let results = match get_aggregate3_results(client.clone(), tx_hash).await {
+            Ok(results) => Ok(results),
+            Err(err) => {
+                log::debug!("Failed to trace tx {:?}, replaying its calls: {}", tx_hash, err);
+                replay_aggregate3_results(
+                    client.clone(),
+                    multicall_address,
+                    signer.address(),
+                    chunk.to_vec(),
+                    inclusion.block_number,
+                )
+                .await
+            }
+        };
+        match results {
+            Ok(results) => {
+                let num_succeeded = results.iter().filter(|result| result.success).count();
+                println!(
+                    "\'aggregate3\' tx {:?} (block #{}, index {}): {}/{} calls succeeded, gas used: {}",
+                    tx_hash,
+                    inclusion.block_number,
+                    inclusion.index,
+                    num_succeeded,
+                    results.len(),
+                    tx_gas_used
+                );
+                succeeded += num_succeeded;
+                failed += results.len() - num_succeeded;
+            }
+            Err(err) => {
+                println!(
+                    "\'aggregate3\' tx {:?} (block #{}, index {}): results of its {} calls unavailable ({}), gas used: {}",
+                    tx_hash, inclusion.block_number, inclusion.index, num_calls, err, tx_gas_used
+                );
+                undecoded += num_calls;
+            }
+        }
+    }
+    println!(
+        "\'{}\' calls: {} succeeded, {} failed, {} w/o results, {} gas used in total (~{} per call)",
+        call.name,
+        succeeded,
+        failed,
+        undecoded,
+        gas_used,
+        gas_used / (succeeded + failed + undecoded).max(1)
+    );
+
+    Ok(())
+}
+
+/// Decode the `Result[]` returned by an `aggregate3` tx from its call trace. The node must support
+/// `debug_traceTransaction` w/ the built-in `callTracer`.
+async fn get_aggregate3_results(
+    client: Arc<Client>,
+    tx_hash: TxHash,
+) -> eyre::Result<Vec<CallResult>> {
+    let options = GethDebugTracingOptions {
+        tracer: Some(GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::CallTracer)),
+        ..Default::default()
+    };
+    let output = match client.debug_trace_transaction(tx_hash, options).await? {
+        GethTrace::Known(GethTraceFrame::CallTracer(frame)) => frame.output,
+        trace => eyre::bail!("Unexpected trace of tx {:?}: {:?}", tx_hash, trace),
+    }
+    .ok_or_else(|| eyre::eyre!("No output in the trace of tx {:?}", tx_hash))?;
+
+    Ok(Aggregate3Return::decode(output)?.return_data)
+}
+
+/// Replay the calls of an `aggregate3` tx from `from` via `eth_call` on top of the parent of the
+/// block it landed in, and decode the `Result[]` returned. This approximates the tx's results, as
+/// the txs before it in its block are left out.
+async fn replay_aggregate3_results(
+    client: Arc<Client>,
+    multicall_address: Address,
+    from: Address,
+    calls: Vec<Call3>,
+    block_number: U64,
+) -> eyre::Result<Vec<CallResult>> {
+    let tx: TypedTransaction = TransactionRequest::new()
+        .from(from)
+        .to(multicall_address)
+        .data(Aggregate3Call { calls }.encode())
+        .into();
+    let parent = BlockId::from(block_number.saturating_sub(U64::one()));
+    let output = client.call(&tx, Some(parent)).await?;
+
+    Ok(Aggregate3Return::decode(output)?.return_data)
+}
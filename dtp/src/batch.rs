@@ -0,0 +1,245 @@
+use crate::retry::{already_known_tx_hash, RetryPolicy, RetryStats, RpcErrorClass};
+use ethers::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, value::RawValue, Value};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// Interval (in ms) between 2 polls of the receipts of the pending txs.
+const RECEIPT_POLL_INTERVAL_MS: u64 = 1_000;
+
+/// A call of a JSON-RPC batch request, as answered by the node.
+#[derive(Debug, Deserialize)]
+struct BatchResponseItem {
+    id: usize,
+    #[serde(default)]
+    result: Option<Box<RawValue>>,
+    #[serde(default)]
+    error: Option<JsonRpcError>,
+}
+
+/// Client packing many calls of the same method into single JSON-RPC batch requests (arrays of
+/// up to `batch_size` calls) over HTTP, so that many txs cost one request (and connection) instead
+/// of one each.
+///
+/// Each call of a batch fails or succeeds on its own. A whole batch request failing with a
+/// transient error (rate-limited, connection reset) is retried as per the `RetryPolicy`, and
+/// recorded in the same `RetryStats` as the single requests.
+///
+/// Batches are spread round-robin across the RPC node URLs.
+#[derive(Debug)]
+pub(crate) struct BatchClient {
+    http: reqwest::Client,
+    urls: Vec<reqwest::Url>,
+    next: AtomicUsize,
+    batch_size: usize,
+    policy: RetryPolicy,
+    stats: Arc<RetryStats>,
+}
+
+impl BatchClient {
+    /// `ws(s)://` RPC node URLs are swapped for `http(s)://`, as batches are sent over HTTP.
+    pub(crate) fn new(
+        rpc_urls: &[String],
+        batch_size: u16,
+        policy: RetryPolicy,
+        stats: Arc<RetryStats>,
+    ) -> eyre::Result<Self> {
+        eyre::ensure!(batch_size > 0, "JSON-RPC batch size must be > 0");
+        let urls = rpc_urls
+            .iter()
+            .map(|rpc_url| {
+                let http_url = if rpc_url.starts_with("ws") {
+                    rpc_url.replacen("ws", "http", 1)
+                } else {
+                    rpc_url.clone()
+                };
+                Ok(http_url.parse()?)
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            http: reqwest::Client::new(),
+            urls,
+            next: AtomicUsize::new(0),
+            batch_size: batch_size.into(),
+            policy,
+            stats,
+        })
+    }
+
+    /// Send `method` once per params in `params_list`, in batches of `batch_size` calls. The
+    /// results are in the same order as `params_list`.
+    ///
+    /// A batch retried after a transient failure may have reached the node on a previous attempt.
+    /// So, a call failing in a retried batch is answered by `recover` (given the call's index in
+    /// `params_list` & its error) instead, if it returns a result.
+    async fn call_batched<R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params_list: Vec<Value>,
+        recover: impl Fn(usize, &JsonRpcError) -> Option<R>,
+    ) -> eyre::Result<Vec<Result<R, JsonRpcError>>> {
+        let mut results = Vec::with_capacity(params_list.len());
+        for chunk in params_list.chunks(self.batch_size) {
+            let calls: Vec<Value> = chunk
+                .iter()
+                .enumerate()
+                .map(|(id, params)| {
+                    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+                })
+                .collect();
+            let (mut items, retried) = self.send_batch(method, &calls).await?;
+            eyre::ensure!(
+                items.len() == calls.len(),
+                "Got {} results for a batch of {} \'{}\' calls",
+                items.len(),
+                calls.len(),
+                method
+            );
+
+            // the node may answer the calls of a batch in any order
+            items.sort_by_key(|item| item.id);
+            for item in items {
+                let result = match item.error {
+                    Some(err) => match retried.then(|| recover(results.len(), &err)).flatten() {
+                        Some(result) => Ok(result),
+                        None => Err(err),
+                    },
+                    None => {
+                        let raw = item.result.as_ref().map_or("null", |result| result.get());
+                        Ok(serde_json::from_str(raw)?)
+                    }
+                };
+                results.push(result);
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Send a batch request, retrying it on transient failures. Returns whether it was retried.
+    async fn send_batch(
+        &self,
+        method: &str,
+        calls: &[Value],
+    ) -> eyre::Result<(Vec<BatchResponseItem>, bool)> {
+        let mut attempt = 0;
+        loop {
+            let url = &self.urls[self.next.fetch_add(1, Ordering::Relaxed) % self.urls.len()];
+            let err = match self.try_send_batch(url, calls).await {
+                Ok(items) => return Ok((items, attempt > 0)),
+                Err(err) => err,
+            };
+
+            let class = RpcErrorClass::classify_msg(&err.to_string());
+            if !class.is_transient() || attempt >= self.policy.max_retries {
+                self.stats.record_failure(class);
+                return Err(err);
+            }
+
+            self.stats.record_retry(class);
+            log::debug!(
+                "Retrying batch of {} \'{}\' calls after {:?} failure: {}",
+                calls.len(),
+                method,
+                class,
+                err
+            );
+            tokio::time::sleep(self.policy.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    async fn try_send_batch(
+        &self,
+        url: &reqwest::Url,
+        calls: &[Value],
+    ) -> eyre::Result<Vec<BatchResponseItem>> {
+        let res = self.http.post(url.clone()).json(calls).send().await?;
+        let status = res.status();
+        let body = res.text().await?;
+        eyre::ensure!(status.is_success(), "HTTP {}: {}", status, body);
+
+        // a node not supporting batches (or rejecting the whole batch) answers w/ a single error
+        serde_json::from_str(&body)
+            .map_err(|_| eyre::eyre!("Batch request rejected by \'{}\': {}", url, body))
+    }
+
+    /// Send the raw txs via batched `eth_sendRawTransaction` calls.
+    pub(crate) async fn send_raw_transactions(
+        &self,
+        raw_txs: &[Bytes],
+    ) -> eyre::Result<Vec<Result<TxHash, JsonRpcError>>> {
+        let params_list = raw_txs.iter().map(|raw_tx| json!([raw_tx])).collect();
+
+        // as for the single requests (see `RetryTransport`), a raw tx already known to the node
+        // reached it on a previous attempt
+        self.call_batched("eth_sendRawTransaction", params_list, |tx, err| {
+            already_known_tx_hash(&err.message, &raw_txs[tx])
+        })
+        .await
+    }
+
+    /// Get the receipts of the txs via batched `eth_getTransactionReceipt` calls. A tx not
+    /// included yet has no receipt.
+    pub(crate) async fn get_transaction_receipts(
+        &self,
+        tx_hashes: &[TxHash],
+    ) -> eyre::Result<Vec<Result<Option<TransactionReceipt>, JsonRpcError>>> {
+        let params_list = tx_hashes.iter().map(|tx_hash| json!([tx_hash])).collect();
+
+        self.call_batched("eth_getTransactionReceipt", params_list, |_, _| None).await
+    }
+}
+
+/// Poll the receipts of `tx_hashes` in batches until all of them are included, or
+/// `inclusion_timeout` elapses. Returns the receipts found.
+///
+/// The txs of a batch that failed as a whole are polled again, like those w/o a receipt yet.
+pub(crate) async fn poll_receipts(
+    batch_client: &BatchClient,
+    tx_hashes: &[TxHash],
+    inclusion_timeout: Duration,
+) -> Vec<TransactionReceipt> {
+    let deadline = tokio::time::Instant::now() + inclusion_timeout;
+    let mut pending = tx_hashes.to_vec();
+    let mut receipts = Vec::with_capacity(tx_hashes.len());
+    while !pending.is_empty() && tokio::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(RECEIPT_POLL_INTERVAL_MS)).await;
+
+        let mut still_pending = Vec::with_capacity(pending.len());
+        // a failed batch only leaves its txs pending, to be polled again
+        for chunk in pending.chunks(batch_client.batch_size) {
+            let results = match batch_client.get_transaction_receipts(chunk).await {
+                Ok(results) => results,
+                Err(e) => {
+                    log::debug!("Failed to get receipts of {} txs: {}", chunk.len(), e);
+                    still_pending.extend_from_slice(chunk);
+                    continue;
+                }
+            };
+            for (&tx_hash, result) in chunk.iter().zip(results) {
+                match result {
+                    Ok(Some(receipt)) => receipts.push(receipt),
+                    Ok(None) => still_pending.push(tx_hash),
+                    Err(e) => {
+                        log::debug!("Failed to get receipt of tx {:?}: {}", tx_hash, e);
+                        still_pending.push(tx_hash);
+                    }
+                }
+            }
+        }
+        pending = still_pending;
+    }
+    if !pending.is_empty() {
+        log::debug!("{} txs not included within {:?}", pending.len(), inclusion_timeout);
+    }
+
+    receipts
+}
@@ -0,0 +1,237 @@
+use crate::{
+    fees::{signer_middleware, TxFees},
+    scheduler::get_block_gas_limit,
+    transport::Client,
+    utils::{track_inclusion, Senders},
+};
+use ethers::{
+    core::{
+        k256::ecdsa::SigningKey,
+        rand::{thread_rng, RngCore},
+    },
+    prelude::*,
+    signers::Wallet,
+};
+use futures::future::join_all;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::Duration,
+};
+
+/// Base gas of a tx.
+const TX_BASE_GAS: u64 = 21_000;
+
+/// Gas per zero & non-zero calldata byte (EIP-2028).
+const CALLDATA_ZERO_BYTE_GAS: u64 = 4;
+const CALLDATA_NON_ZERO_BYTE_GAS: u64 = 16;
+
+/// Intrinsic gas of a tx carrying `data` w/o executing any code.
+fn intrinsic_gas(data: &[u8]) -> U256 {
+    let zero_bytes = data.iter().filter(|byte| **byte == 0).count() as u64;
+    let non_zero_bytes = data.len() as u64 - zero_bytes;
+
+    U256::from(
+        TX_BASE_GAS
+            + zero_bytes * CALLDATA_ZERO_BYTE_GAS
+            + non_zero_bytes * CALLDATA_NON_ZERO_BYTE_GAS,
+    )
+}
+
+/// Outcome of the CALLDATA txs of a payload size.
+#[derive(Debug, Default)]
+struct SizeStats {
+    accepted: usize,
+    rejected: usize,
+    /// Error of the first rejected tx.
+    rejection: Option<String>,
+    /// No. of included txs per block.
+    per_block: BTreeMap<U64, usize>,
+}
+
+/// send a tx carrying `data` as calldata to `to` with the given `nonce` w/o waiting for its
+/// receipt. W/o `gas`, it's estimated.
+/// NOTE: signer needed as it incurs gas fees.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn calldata_send(
+    client: Arc<Client>,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    to: Address,
+    data: Bytes,
+    gas: Option<U256>,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client, signer, chain_id, fees);
+
+    let mut tx = TransactionRequest::new().to(to).data(data).nonce(nonce);
+    if let Some(gas) = gas {
+        tx = tx.gas(gas);
+    }
+    let tx_hash = client_middleware.send_transaction(tx, None).await?.tx_hash();
+
+    Ok(tx_hash)
+}
+
+/// As CALLDATA transaction type, the txs of the new accounts carry random payloads of the given
+/// `sizes` (in bytes) to the `to` sink, in batches of `MAX_BATCH_SIZE`. The txs are split evenly
+/// across the sizes, sent in ascending order of size.
+///
+/// The gas of a tx to a sink w/o code is its intrinsic gas (fixed per payload), otherwise it's
+/// estimated. A tx rejected by the node (e.g. over its max. tx size) is recorded for its size,
+/// rather than failing the run, and its nonce is handed back for the next tx of its sender.
+///
+/// The accepted & rejected txs, the gas charged per byte and the txs included per block are
+/// reported per size.
+pub(crate) async fn multicall_calldata_txs(
+    client: Arc<Client>,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    sizes: &[usize],
+    to: Address,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let mut sizes = sizes.to_vec();
+    sizes.sort_unstable();
+    sizes.dedup();
+    let num_txs = senders.num_txs();
+    eyre::ensure!(
+        !sizes.is_empty() && num_txs >= sizes.len(),
+        "{} txs can't be split across {} payload sizes",
+        num_txs,
+        sizes.len()
+    );
+    let fixed_gas = client.get_code(to, None).await?.is_empty();
+    if !fixed_gas {
+        println!("Sink {:?} has code, so the gas of each tx is estimated", to);
+    }
+
+    let mut stats: BTreeMap<usize, SizeStats> = BTreeMap::new();
+    let mut sent: HashMap<TxHash, usize> = HashMap::with_capacity(num_txs);
+    let ((), report) =
+        track_inclusion(client.clone(), inclusion_timeout, "calldata", async |tracker| {
+            for (i, &size) in sizes.iter().enumerate() {
+                // a random (so mostly non-zero) payload, shared by the txs of this size
+                let mut payload = vec![0_u8; size];
+                thread_rng().fill_bytes(&mut payload);
+                let gas = fixed_gas.then(|| intrinsic_gas(&payload));
+                let data = Bytes::from(payload);
+
+                let size_stats = stats.entry(size).or_default();
+                let txs: Vec<usize> =
+                    (i * num_txs / sizes.len()..(i + 1) * num_txs / sizes.len()).collect();
+                for chunk in txs.chunks(max_batch_size.into()) {
+                    let batch = chunk.iter().map(|&tx| {
+                        let client = client.clone();
+                        let senders = senders.clone();
+                        let signer = senders.signer(tx).to_owned();
+                        let data = data.clone();
+                        async move {
+                            senders
+                                .nonces
+                                .send(client.as_ref(), signer.address(), |nonce| {
+                                    calldata_send(
+                                        client.clone(),
+                                        signer.clone(),
+                                        senders.chain_id,
+                                        &senders.fees,
+                                        to,
+                                        data.clone(),
+                                        gas,
+                                        nonce,
+                                    )
+                                })
+                                .await
+                        }
+                    });
+
+                    // A rejected tx is recorded, and doesn't stop the run
+                    for (&tx, res) in chunk.iter().zip(join_all(batch).await) {
+                        match res {
+                            Ok(tx_hash) => {
+                                tracker.track(tx_hash);
+                                sent.insert(tx_hash, size);
+                                size_stats.accepted += 1;
+                            }
+                            Err(err) => {
+                                log::debug!("{}-byte tx #{} rejected: {}", size, tx, err);
+                                size_stats.rejection.get_or_insert_with(|| err.to_string());
+                                size_stats.rejected += 1;
+                            }
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+        .await?;
+
+    // the included txs & their receipts, per size
+    for (tx_hash, inclusion) in report.included.iter() {
+        let size_stats = stats.get_mut(&sent[tx_hash]).expect("size of each sent tx is known");
+        *size_stats.per_block.entry(inclusion.block_number).or_default() += 1;
+    }
+    let receipt_stats = report.receipt_stats_by(|tx_hash| sent[tx_hash]);
+
+    let block_gas_limit = get_block_gas_limit(client.clone()).await?;
+    println!(
+        "=====\n{:>10} {:>9} {:>9} {:>9} {:>12} {:>10} {:>14} {:>12}",
+        "size (B)",
+        "accepted",
+        "rejected",
+        "included",
+        "avg. gas",
+        "gas/byte",
+        "max per block",
+        "fit by gas"
+    );
+    for (size, size_stats) in stats.iter() {
+        let receipt_stats = receipt_stats.get(size).copied().unwrap_or_default();
+        let avg_gas = receipt_stats.avg_gas_used();
+        let gas_per_byte = if receipt_stats.succeeded() > 0 && *size > 0 {
+            format!("{:.2}", avg_gas.as_u64().saturating_sub(TX_BASE_GAS) as f64 / *size as f64)
+        } else {
+            "-".to_string()
+        };
+        let fit_by_gas = if receipt_stats.succeeded() > 0 {
+            (block_gas_limit / avg_gas).to_string()
+        } else {
+            "-".to_string()
+        };
+        println!(
+            "{:>10} {:>9} {:>9} {:>9} {:>12} {:>10} {:>14} {:>12}",
+            size,
+            size_stats.accepted,
+            size_stats.rejected,
+            receipt_stats.included,
+            avg_gas,
+            gas_per_byte,
+            size_stats.per_block.values().max().copied().unwrap_or_default(),
+            fit_by_gas
+        );
+    }
+    for (size, size_stats) in stats.iter() {
+        if let Some(rejection) = &size_stats.rejection {
+            println!("{}-byte txs rejected, e.g.: {}", size, rejection);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intrinsic_gas_prices_zero_and_non_zero_bytes() {
+        assert_eq!(intrinsic_gas(&[]), U256::from(21_000));
+        assert_eq!(intrinsic_gas(&[0; 10]), U256::from(21_000 + 10 * 4));
+        assert_eq!(intrinsic_gas(&[0xff; 10]), U256::from(21_000 + 10 * 16));
+        assert_eq!(intrinsic_gas(&[0, 1, 0, 0x80]), U256::from(21_000 + 2 * 4 + 2 * 16));
+        // a 128 KiB payload of non-zero bytes
+        assert_eq!(intrinsic_gas(&vec![0xab; 128 * 1024]), U256::from(21_000 + 131_072 * 16));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    contracts::{multicall_aggregate3_estimate_gas, multicall_aggregate3_send},
+    transport::Client,
+    utils::{run_tracked, Senders},
+};
+use bindings::{
+    counter::IncrementCall,
+    load::FactorialCall,
+    multicall_3::{Aggregate3Call, Call3},
+};
+use ethers::{abi::AbiEncode, prelude::*};
+use std::{sync::Arc, time::Duration};
+
+/// Largest input of `Load::factorial` whose result fits in a `uint256`. The contract's checked
+/// multiplication reverts beyond it.
+pub(crate) const MAX_FACTORIAL_INPUT: u64 = 57;
+
+/// Call made by each COMPUTE tx: `rounds` x `Load::factorial(factorial_input)`, then a single
+/// `Counter::increment()`, all packed into one Multicall3 `aggregate3` call. The factorials burn
+/// CPU w/o touching the storage, and the final increment makes the tx state-changing w/ one slot
+/// written, however many rounds it runs.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Compute {
+    pub(crate) multicall_address: Address,
+    pub(crate) load_address: Address,
+    pub(crate) counter_address: Address,
+    pub(crate) factorial_input: u64,
+    pub(crate) rounds: u32,
+}
+
+impl Compute {
+    /// The calls packed into the `aggregate3` call. None of them may fail, so that a reverting
+    /// factorial reverts the whole tx.
+    pub(crate) fn calls(&self) -> Vec<Call3> {
+        let factorial = Call3 {
+            target: self.load_address,
+            allow_failure: false,
+            call_data: FactorialCall { num: self.factorial_input.into() }.encode().into(),
+        };
+        let increment = Call3 {
+            target: self.counter_address,
+            allow_failure: false,
+            call_data: IncrementCall.encode().into(),
+        };
+        let mut calls = vec![factorial; self.rounds as usize];
+        calls.push(increment);
+        calls
+    }
+
+    /// Calldata of the `aggregate3` call sent to `multicall_address`
+    pub(crate) fn data(&self) -> Bytes {
+        Aggregate3Call { calls: self.calls() }.encode().into()
+    }
+
+    pub(crate) fn name(&self) -> String {
+        format!(
+            "{} x Load::factorial({}) + Counter::increment()",
+            self.rounds, self.factorial_input
+        )
+    }
+}
+
+/// As COMPUTE transaction type, each new account sends `compute` txs, in batches of
+/// `MAX_BATCH_SIZE`. Each tx loops over `rounds` x `factorial_input` checked multiplications, then
+/// writes a single slot (`Counter`'s `number`), so that most of its gas is spent on computation,
+/// unlike the HEAVY txs (storage writes). The gas is estimated once for all the txs.
+///
+/// The avg. gas used per tx is reported.
+pub(crate) async fn multicall_compute_txs(
+    client: Arc<Client>,
+    compute: &Compute,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let label = compute.name();
+    let calls = compute.calls();
+    let gas = multicall_aggregate3_estimate_gas(
+        client.clone(),
+        compute.multicall_address,
+        senders.signer(0).to_owned(),
+        senders.chain_id,
+        &senders.fees,
+        calls.clone(),
+    )
+    .await?;
+
+    let txs: Vec<usize> = (0..senders.num_txs()).collect();
+    let (_, report) = run_tracked(
+        client.clone(),
+        &senders,
+        &txs,
+        max_batch_size,
+        inclusion_timeout,
+        &label,
+        |_, signer, nonce| {
+            multicall_aggregate3_send(
+                client.clone(),
+                compute.multicall_address,
+                signer,
+                senders.chain_id,
+                &senders.fees,
+                calls.clone(),
+                gas,
+                nonce,
+            )
+        },
+    )
+    .await?;
+    report.print_receipts(&label);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_calls() {
+        let compute = Compute {
+            multicall_address: Address::repeat_byte(1),
+            load_address: Address::repeat_byte(2),
+            counter_address: Address::repeat_byte(3),
+            factorial_input: 57,
+            rounds: 3,
+        };
+        let calls = compute.calls();
+
+        // the rounds of factorials, then the single write
+        assert_eq!(calls.len(), 4);
+        assert!(calls[..3].iter().all(|call| call.target == compute.load_address));
+        assert_eq!(calls[3].target, compute.counter_address);
+        assert!(calls.iter().all(|call| !call.allow_failure));
+    }
+}
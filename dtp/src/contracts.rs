@@ -1,5 +1,15 @@
-use crate::utils::wei_to_tssc_f64;
-use bindings::{counter::Counter, load::Load};
+use crate::{
+    fees::{signer_middleware, TxFees},
+    transport::Client,
+    utils::wei_to_tssc_f64,
+};
+use bindings::{
+    counter::Counter,
+    erc20::ERC20,
+    fund::{Fund, FundErrors},
+    load::Load,
+    multicall_3::{Call3, Multicall3},
+};
 use ethers::{core::k256::ecdsa::SigningKey, prelude::*, signers::Wallet};
 use log::debug;
 use std::sync::Arc;
@@ -7,7 +17,7 @@ use std::sync::Arc;
 /// get Counter number
 /// NOTE: No signer needed as it is gasless call.
 pub(crate) async fn counter_get_number(
-    client: Arc<Provider<Http>>,
+    client: Arc<Client>,
     counter_address: Address,
 ) -> eyre::Result<U256> {
     let counter = Counter::new(counter_address, client);
@@ -16,129 +26,356 @@ pub(crate) async fn counter_get_number(
 
     Ok(num)
 }
-/// set Counter number
+/// send `Counter::setNumber(number)` tx with the given `nonce` w/o waiting for its receipt
 /// NOTE: signer needed as it incurs gas fees.
-#[allow(dead_code)]
-pub(crate) async fn counter_set_number(
-    client: Arc<Provider<Http>>,
+pub(crate) async fn counter_set_number_send(
+    client: Arc<Client>,
     counter_address: Address,
     signer: Wallet<SigningKey>,
     chain_id: u64,
-) -> eyre::Result<()> {
+    fees: &TxFees,
+    number: U256,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
     // create a middleware client with signature from signer & provider
-    let client_middleware = SignerMiddleware::new(client.clone(), signer.with_chain_id(chain_id));
+    let client_middleware = signer_middleware(client.clone(), signer, chain_id, fees);
 
     // get a contract
     let counter = Counter::new(counter_address, Arc::new(client_middleware));
 
-    // send a transaction with setter function
-    let tx_receipt = counter
-        .set_number(U256::from(42))
-        .send()
-        .await?
-        .await?
-        .expect("Failure in \'setNumber\' method of Counter contract");
-    log_tx_dbg(tx_receipt, format!("Counter::setNumber({})", 42).as_str());
+    // send a transaction with setter function, but don't await the pending tx
+    let tx_hash = counter.set_number(number).nonce(nonce).send().await?.tx_hash();
 
-    Ok(())
+    Ok(tx_hash)
 }
 
-/// increment Counter number
+/// send `Counter::increment` tx with the given `nonce` w/o waiting for its receipt
 /// NOTE: signer needed as it incurs gas fees.
-pub(crate) async fn counter_increment(
-    client: Arc<Provider<Http>>,
+pub(crate) async fn counter_increment_send(
+    client: Arc<Client>,
     counter_address: Address,
     signer: Wallet<SigningKey>,
     chain_id: u64,
-) -> eyre::Result<()> {
+    fees: &TxFees,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
     // create a middleware client with signature from signer & provider
-    let client_middleware = SignerMiddleware::new(client.clone(), signer.with_chain_id(chain_id));
+    let client_middleware = signer_middleware(client.clone(), signer, chain_id, fees);
 
     // get a contract
     let counter = Counter::new(counter_address, Arc::new(client_middleware));
 
-    // send a transaction with setter function
-    let tx_receipt = counter
-        .increment()
-        .send()
-        .await
-        .expect("Failure in getting pending tx")
-        .await?
-        .expect("Failure in \'increment\' method of Counter contract");
+    // send a transaction with setter function, but don't await the pending tx
+    let tx_hash = counter.increment().nonce(nonce).send().await?.tx_hash();
 
-    log_tx_dbg(tx_receipt, "Counter::increment()");
-
-    Ok(())
+    Ok(tx_hash)
 }
 
 /// Get balance of address
-async fn get_balance(client: Arc<Provider<Http>>, of: Address) -> eyre::Result<U256> {
-    let balance = client
-        .get_balance(of, None)
-        .await
-        .expect(format!("Failed to get the balance of {}", of).as_str());
+async fn get_balance(client: Arc<Client>, of: Address) -> eyre::Result<U256> {
+    let balance = client.get_balance(of, None).await?;
 
     Ok(balance)
 }
 
-/// Load contract: `setArray` method
-/// NOTE: signer needed as it incurs gas fees.
-pub(crate) async fn load_set_array(
-    client: Arc<Provider<Http>>,
+/// Estimate gas of Load contract's `setArray` method for `signer`, also checking that the signer
+/// has sufficient balance to pay for it at `max_gas_price` (resolved once per run).
+pub(crate) async fn load_set_array_estimate_gas(
+    client: Arc<Client>,
     load_address: Address,
     signer: Wallet<SigningKey>,
     chain_id: u64,
-    max_load_count_per_block: u16,
-) -> eyre::Result<()> {
+    fees: &TxFees,
+    count: u16,
+    max_gas_price: U256,
+) -> eyre::Result<U256> {
     // create a middleware client with signature from signer & provider
-    let client_middleware =
-        SignerMiddleware::new(client.clone(), signer.clone().with_chain_id(chain_id));
+    let client_middleware = signer_middleware(client.clone(), signer.clone(), chain_id, fees);
 
     // get a contract
     let load = Load::new(load_address, Arc::new(client_middleware));
 
-    // TODO: Here, `count` can be abstracted out as CLI parameter with default value set as may be `1000`
-    // considered the highest possible count per block for now.
-    let count = max_load_count_per_block;
-
     // check for estimated balance
-    let from_balance_pre = get_balance(client, signer.address()).await?;
+    let from_balance_pre = get_balance(client.clone(), signer.address()).await?;
     let estimated_gas = load.set_array(U256::from(count)).estimate_gas().await?;
     debug!("Estimated gas: {}", estimated_gas);
-    // set gas price to 3.5 Gwei for heavy tx type
-    let estimated_gas_price = estimated_gas.checked_mul(U256::from(3500000000_u32)).unwrap();
-    assert!(
+    // at the max. gas price of the tx envelope
+    let estimated_gas_price = estimated_gas
+        .checked_mul(max_gas_price)
+        .ok_or_else(|| eyre::eyre!("[Load] Overflow in multiplying est. gas w/ gas price"))?;
+    eyre::ensure!(
         from_balance_pre >= estimated_gas_price,
         "Balance short by: {}",
-        estimated_gas_price
-            .checked_sub(from_balance_pre)
-            .expect("[Load] Error in subtracting bal. from est. gas price"),
+        estimated_gas_price - from_balance_pre
     );
 
-    debug!("[Pre-tx] Est. gas price: {}", estimated_gas_price,);
+    Ok(estimated_gas)
+}
+
+/// send Load contract's `setArray` tx with the given `gas` limit & `nonce` w/o waiting for its
+/// receipt
+/// NOTE: signer needed as it incurs gas fees.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn load_set_array_send(
+    client: Arc<Client>,
+    load_address: Address,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    count: u16,
+    gas: U256,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client.clone(), signer, chain_id, fees);
+
+    // get a contract
+    let load = Load::new(load_address, Arc::new(client_middleware));
+
+    // send a transaction with setter function, but don't await the pending tx.
+    // The gas is already estimated (for packing into blocks), so not estimated again.
+    let tx_hash = load.set_array(U256::from(count)).gas(gas).nonce(nonce).send().await?.tx_hash();
+
+    Ok(tx_hash)
+}
+
+/// Estimate gas of Multicall3 contract's `aggregate3` method packing `calls` for `signer`
+pub(crate) async fn multicall_aggregate3_estimate_gas(
+    client: Arc<Client>,
+    multicall_address: Address,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    calls: Vec<Call3>,
+) -> eyre::Result<U256> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client.clone(), signer, chain_id, fees);
+
+    // get a contract
+    let multicall = Multicall3::new(multicall_address, Arc::new(client_middleware));
+
+    let estimated_gas = multicall.aggregate_3(calls).estimate_gas().await?;
+    debug!("Estimated gas: {}", estimated_gas);
+
+    Ok(estimated_gas)
+}
+
+/// send Multicall3 contract's `aggregate3` tx packing `calls` with the given `gas` limit & `nonce`
+/// w/o waiting for its receipt
+/// NOTE: signer needed as it incurs gas fees.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn multicall_aggregate3_send(
+    client: Arc<Client>,
+    multicall_address: Address,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    calls: Vec<Call3>,
+    gas: U256,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client.clone(), signer, chain_id, fees);
+
+    // get a contract
+    let multicall = Multicall3::new(multicall_address, Arc::new(client_middleware));
+
+    // send a transaction with the aggregating function, but don't await the pending tx.
+    // The gas is already estimated (for sizing the tx to the block gas limit), so not estimated
+    // again.
+    let tx_hash = multicall.aggregate_3(calls).gas(gas).nonce(nonce).send().await?.tx_hash();
+
+    Ok(tx_hash)
+}
+
+/// simulate Fund contract's `transferTsscToMany` call from `from`, sending `value` to split
+/// across `tos`. Returns the contract's error it reverts with, if any.
+/// NOTE: No signer needed as it is gasless call.
+pub(crate) async fn fund_transfer_tssc_to_many_revert(
+    client: Arc<Client>,
+    fund_address: Address,
+    from: Address,
+    tos: Vec<Address>,
+    value: U256,
+) -> eyre::Result<Option<FundErrors>> {
+    let fund = Fund::new(fund_address, client);
+
+    match fund.transfer_tssc_to_many(tos).value(value).from(from).call().await {
+        Ok(()) => Ok(None),
+        Err(err) => match err.decode_contract_revert::<FundErrors>() {
+            Some(fund_error) => Ok(Some(fund_error)),
+            None => Err(err.into()),
+        },
+    }
+}
+
+/// deploy ERC20 token contract & wait for it to be included
+/// NOTE: signer needed as it incurs gas fees.
+pub(crate) async fn erc20_deploy(
+    client: Arc<Client>,
+    deployer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+) -> eyre::Result<Address> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client, deployer, chain_id, fees);
+
+    // send the deployment tx & await its receipt
+    let token = ERC20::deploy(Arc::new(client_middleware), ())?.send().await?;
+
+    Ok(token.address())
+}
+
+/// send ERC20 token's `mint` tx with the given `nonce` w/o waiting for its receipt
+/// NOTE: signer needed as it incurs gas fees.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn erc20_mint_send(
+    client: Arc<Client>,
+    token_address: Address,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    to: Address,
+    amount: U256,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client, signer, chain_id, fees);
+
+    // get a contract
+    let token = ERC20::new(token_address, Arc::new(client_middleware));
+
+    // send a transaction with setter function, but don't await the pending tx
+    let tx_hash = token.mint(to, amount).nonce(nonce).send().await?.tx_hash();
+
+    Ok(tx_hash)
+}
+
+/// send ERC20 token's `approve` tx with the given `nonce` w/o waiting for its receipt
+/// NOTE: signer needed as it incurs gas fees.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn erc20_approve_send(
+    client: Arc<Client>,
+    token_address: Address,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    spender: Address,
+    amount: U256,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client, signer, chain_id, fees);
+
+    // get a contract
+    let token = ERC20::new(token_address, Arc::new(client_middleware));
+
+    // send a transaction with setter function, but don't await the pending tx
+    let tx_hash = token.approve(spender, amount).nonce(nonce).send().await?.tx_hash();
+
+    Ok(tx_hash)
+}
+
+/// send ERC20 token's `transfer` tx with the given `nonce` w/o waiting for its receipt
+/// NOTE: signer needed as it incurs gas fees.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn erc20_transfer_send(
+    client: Arc<Client>,
+    token_address: Address,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    to: Address,
+    amount: U256,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client, signer, chain_id, fees);
+
+    // get a contract
+    let token = ERC20::new(token_address, Arc::new(client_middleware));
+
+    // send a transaction with setter function, but don't await the pending tx
+    let tx_hash = token.transfer(to, amount).nonce(nonce).send().await?.tx_hash();
+
+    Ok(tx_hash)
+}
+
+/// send ERC20 token's `transferFrom` tx with the given `nonce` w/o waiting for its receipt
+/// NOTE: signer (the spender) needed as it incurs gas fees.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn erc20_transfer_from_send(
+    client: Arc<Client>,
+    token_address: Address,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    from: Address,
+    to: Address,
+    amount: U256,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client, signer, chain_id, fees);
+
+    // get a contract
+    let token = ERC20::new(token_address, Arc::new(client_middleware));
+
+    // send a transaction with setter function, but don't await the pending tx
+    let tx_hash = token.transfer_from(from, to, amount).nonce(nonce).send().await?.tx_hash();
+
+    Ok(tx_hash)
+}
+
+/// get ERC20 token balance of `of`
+/// NOTE: No signer needed as it is gasless call.
+pub(crate) async fn erc20_balance_of(
+    client: Arc<Client>,
+    token_address: Address,
+    of: Address,
+) -> eyre::Result<U256> {
+    let token = ERC20::new(token_address, client);
 
-    // send a transaction with setter function
-    let tx_receipt = load
-        // to try out with its different values.
-        // The max. `count` possible in `setArray` method of Load contract is 2650. Above this count value,
-        // the gas cost exceeds 60 M per block (as set for Subspace EVM domain).
-        .set_array(U256::from(count))
-        .send()
-        .await
-        .expect("Failure in getting pending tx")
-        .await?
-        .expect("Failure in \'setArray\' method of Load contract");
+    let balance = token.balance_of(of).call().await?;
+
+    Ok(balance)
+}
+
+/// get ERC20 token's `owner`, the only account allowed to `mint`
+/// NOTE: No signer needed as it is gasless call.
+pub(crate) async fn erc20_owner(
+    client: Arc<Client>,
+    token_address: Address,
+) -> eyre::Result<Address> {
+    let token = ERC20::new(token_address, client);
+
+    let owner = token.owner().call().await?;
+
+    Ok(owner)
+}
+
+/// send a contract creation tx of `bytecode` with the given `nonce` w/o waiting for its receipt
+/// NOTE: signer needed as it incurs gas fees.
+pub(crate) async fn deploy_send(
+    client: Arc<Client>,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    bytecode: Bytes,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client, signer, chain_id, fees);
 
-    // need to check the gas unit if that also changes each call.
-    debug!("[Post-tx] Gas consumed: {}", tx_receipt.gas_used.unwrap_or_default());
-    // log all details regarding the tx
-    log_tx_dbg(tx_receipt, format!("Load::setArray({})", count).as_str());
+    // a tx w/o recipient creates a contract from its data (the constructors take no arguments)
+    let tx = TransactionRequest::new().data(bytecode).nonce(nonce);
+    let tx_hash = client_middleware.send_transaction(tx, None).await?.tx_hash();
 
-    Ok(())
+    Ok(tx_hash)
 }
 
 /// debug! tx details with custom str
-fn log_tx_dbg(tx_receipt: TransactionReceipt, contract_name: &str) {
+pub(crate) fn log_tx_dbg(tx_receipt: TransactionReceipt, contract_name: &str) {
     let message =
         format!(
         "{} ==> from: {}, gas price: {:.18} TSSC, tx hash: {:?}, tx index: {}, block number: {}",
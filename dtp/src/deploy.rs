@@ -0,0 +1,179 @@
+use crate::{
+    contracts::deploy_send,
+    transport::Client,
+    utils::{run_tracked, Senders},
+};
+use bindings::{
+    counter::COUNTER_BYTECODE, erc20::ERC20_BYTECODE, fund::FUND_BYTECODE, load::LOAD_BYTECODE,
+    multicall_3::MULTICALL3_BYTECODE,
+};
+use ethers::prelude::*;
+use futures::future::join_all;
+use std::{collections::BTreeMap, str::FromStr, sync::Arc, time::Duration};
+
+/// Contract(s) deployed by the DEPLOY txs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum DeployContract {
+    Counter,
+    Load,
+    Fund,
+    Multicall3,
+    Erc20,
+    /// Each tx deploys the next of the above contracts in turn.
+    All,
+}
+
+/// Implement `FromStr` trait for DeployContract
+impl FromStr for DeployContract {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "counter" => Ok(DeployContract::Counter),
+            "load" => Ok(DeployContract::Load),
+            "fund" => Ok(DeployContract::Fund),
+            "multicall3" => Ok(DeployContract::Multicall3),
+            "erc20" => Ok(DeployContract::Erc20),
+            "all" => Ok(DeployContract::All),
+            _ => Err(format!("\'{}\' is not a valid DeployContract", s)),
+        }
+    }
+}
+
+impl DeployContract {
+    /// Contracts deployed in turn for `All`.
+    const ALL: [DeployContract; 5] = [
+        DeployContract::Counter,
+        DeployContract::Load,
+        DeployContract::Fund,
+        DeployContract::Multicall3,
+        DeployContract::Erc20,
+    ];
+
+    /// Contract deployed by the `tx`-th tx.
+    fn of_tx(self, tx: usize) -> Self {
+        match self {
+            DeployContract::All => Self::ALL[tx % Self::ALL.len()],
+            contract => contract,
+        }
+    }
+
+    /// Creation bytecode embedded in the contract's binding.
+    fn bytecode(self) -> Bytes {
+        match self {
+            DeployContract::Counter => COUNTER_BYTECODE.clone(),
+            DeployContract::Load => LOAD_BYTECODE.clone(),
+            DeployContract::Fund => FUND_BYTECODE.clone(),
+            DeployContract::Multicall3 => MULTICALL3_BYTECODE.clone(),
+            DeployContract::Erc20 => ERC20_BYTECODE.clone(),
+            DeployContract::All => unreachable!("resolved per tx via `of_tx`"),
+        }
+    }
+}
+
+/// Creation gas & code size of the instances of a contract.
+#[derive(Debug, Default)]
+struct DeployStats {
+    deployed: usize,
+    failed: usize,
+    gas_used: U256,
+    code_size: usize,
+}
+
+/// As DEPLOY transaction type, each new account creates fresh instances of `contract` from the
+/// bytecode embedded in the bindings, in batches of `MAX_BATCH_SIZE`.
+///
+/// The code of each created instance is fetched to report its address, creation gas & code size.
+pub(crate) async fn multicall_deploy_txs(
+    client: Arc<Client>,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    contract: DeployContract,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let txs: Vec<usize> = (0..senders.num_txs()).collect();
+
+    let (sent, report) = run_tracked(
+        client.clone(),
+        &senders,
+        &txs,
+        max_batch_size,
+        inclusion_timeout,
+        "deploy",
+        |tx, signer, nonce| {
+            deploy_send(
+                client.clone(),
+                signer,
+                senders.chain_id,
+                &senders.fees,
+                contract.of_tx(tx).bytecode(),
+                nonce,
+            )
+        },
+    )
+    .await?;
+    let deployments: Vec<(DeployContract, TxHash)> =
+        sent.into_iter().map(|(tx, tx_hash)| (contract.of_tx(tx), tx_hash)).collect();
+
+    // fetch the code of each included deployment in chunks of `MAX_BATCH_SIZE`
+    let included: Vec<(DeployContract, &TransactionReceipt)> = deployments
+        .into_iter()
+        .filter_map(|(contract, tx_hash)| {
+            report.receipts.get(&tx_hash).map(|tx_receipt| (contract, tx_receipt))
+        })
+        .collect();
+    let mut stats: BTreeMap<DeployContract, DeployStats> = BTreeMap::new();
+    for chunk in included.chunks(max_batch_size.into()) {
+        let batch = chunk.iter().map(|&(contract, tx_receipt)| {
+            let client = client.clone();
+            async move {
+                let code_size = match tx_receipt.contract_address {
+                    Some(address) => client.get_code(address, None).await?.len(),
+                    None => 0,
+                };
+                Ok::<_, eyre::Report>((contract, tx_receipt, code_size))
+            }
+        });
+
+        for res in join_all(batch).await {
+            let (contract, tx_receipt, code_size) = res?;
+            let contract_stats = stats.entry(contract).or_default();
+            match tx_receipt.contract_address {
+                Some(address) if tx_receipt.status == Some(U64::one()) => {
+                    let gas_used = tx_receipt.gas_used.unwrap_or_default();
+                    println!(
+                        "{:?} deployed at {:?} (creation gas: {}, code size: {} bytes)",
+                        contract, address, gas_used, code_size
+                    );
+                    contract_stats.deployed += 1;
+                    contract_stats.gas_used += gas_used;
+                    contract_stats.code_size += code_size;
+                }
+                _ => {
+                    println!(
+                        "{:?} deployment failed, tx hash: {:?}",
+                        contract, tx_receipt.transaction_hash
+                    );
+                    contract_stats.failed += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "=====\n{:<12} {:>10} {:>8} {:>16} {:>16}",
+        "contract", "deployed", "failed", "avg. gas", "code (bytes)"
+    );
+    for (contract, contract_stats) in stats.iter() {
+        println!(
+            "{:<12} {:>10} {:>8} {:>16} {:>16}",
+            format!("{:?}", contract),
+            contract_stats.deployed,
+            contract_stats.failed,
+            contract_stats.gas_used / contract_stats.deployed.max(1),
+            contract_stats.code_size
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,317 @@
+use crate::{
+    contracts::{
+        erc20_approve_send, erc20_balance_of, erc20_deploy, erc20_mint_send, erc20_owner,
+        erc20_transfer_from_send, erc20_transfer_send,
+    },
+    transport::Client,
+    utils::{run_tracked, Senders},
+};
+use ethers::{core::k256::ecdsa::SigningKey, prelude::*, signers::Wallet, utils::format_ether};
+use futures::future::join_all;
+use std::{collections::HashSet, sync::Arc, time::Duration};
+
+/// Call of the ERC-20 token made by a tx.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Erc20Op {
+    /// `mint(account, amount)`, only allowed to the token's owner
+    Mint(Address),
+    /// `approve(next signer, MAX)`
+    Approve,
+    /// `transfer(next signer, amount)`
+    Transfer,
+    /// `transferFrom(previous signer, signer, amount)`, as approved by the previous signer
+    TransferFrom,
+}
+
+impl Erc20Op {
+    /// Op of the `tx`-th tx of the workload phase, out of `num_signers` round-robin senders. The
+    /// ops rotate both across the accounts & across their rounds of txs, so that each account
+    /// makes all of them over any 3 of its consecutive txs, and so do any 3 consecutive txs of a
+    /// round.
+    fn of_tx(tx: usize, num_signers: usize) -> Self {
+        match (tx % num_signers + tx / num_signers) % 3 {
+            0 => Erc20Op::Transfer,
+            1 => Erc20Op::Approve,
+            _ => Erc20Op::TransferFrom,
+        }
+    }
+}
+
+/// Send `op` as the `tx`-th tx, signed by its `signer` w/ `nonce`.
+#[allow(clippy::too_many_arguments)]
+async fn send_erc20_op(
+    client: Arc<Client>,
+    token_address: Address,
+    senders: &Senders,
+    tx: usize,
+    signer: Wallet<SigningKey>,
+    op: Erc20Op,
+    amount: U256,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    let num_signers = senders.signers.len();
+    let next = senders.signer(tx + 1).address();
+    let previous = senders.signer(tx + num_signers - 1).address();
+    let chain_id = senders.chain_id;
+    let fees = &senders.fees;
+
+    match op {
+        Erc20Op::Mint(to) => {
+            erc20_mint_send(client, token_address, signer, chain_id, fees, to, amount, nonce).await
+        }
+        Erc20Op::Approve => {
+            erc20_approve_send(
+                client,
+                token_address,
+                signer,
+                chain_id,
+                fees,
+                next,
+                U256::MAX,
+                nonce,
+            )
+            .await
+        }
+        Erc20Op::Transfer => {
+            erc20_transfer_send(client, token_address, signer, chain_id, fees, next, amount, nonce)
+                .await
+        }
+        Erc20Op::TransferFrom => {
+            let to = signer.address();
+            erc20_transfer_from_send(
+                client,
+                token_address,
+                signer,
+                chain_id,
+                fees,
+                previous,
+                to,
+                amount,
+                nonce,
+            )
+            .await
+        }
+    }
+}
+
+/// Get the total token balance of `addresses`, fetched in chunks of `MAX_BATCH_SIZE`.
+async fn get_total_token_balance(
+    client: Arc<Client>,
+    token_address: Address,
+    addresses: &[Address],
+    max_batch_size: u16,
+) -> eyre::Result<U256> {
+    let mut total = U256::zero();
+    for chunk in addresses.chunks(max_batch_size.into()) {
+        let batch =
+            chunk.iter().map(|address| erc20_balance_of(client.clone(), token_address, *address));
+        for balance in join_all(batch).await {
+            total += balance?;
+        }
+    }
+
+    Ok(total)
+}
+
+/// Send the `txs`, each making the op `op_of(tx)`, in batches of `MAX_BATCH_SIZE`, tracking their
+/// inclusion, and report them under `label`.
+#[allow(clippy::too_many_arguments)]
+async fn send_erc20_ops(
+    client: Arc<Client>,
+    token_address: Address,
+    senders: &Senders,
+    txs: &[usize],
+    op_of: impl Fn(usize) -> Erc20Op,
+    amount: U256,
+    max_batch_size: u16,
+    inclusion_timeout: Duration,
+    label: &str,
+) -> eyre::Result<()> {
+    let (_, report) = run_tracked(
+        client.clone(),
+        senders,
+        txs,
+        max_batch_size,
+        inclusion_timeout,
+        label,
+        |tx, signer, nonce| {
+            send_erc20_op(
+                client.clone(),
+                token_address,
+                senders,
+                tx,
+                signer,
+                op_of(tx),
+                amount,
+                nonce,
+            )
+        },
+    )
+    .await?;
+    eyre::ensure!(
+        report.not_included.is_empty() && report.failed == 0,
+        "{} \'{}\' txs not included, {} failed to be sent",
+        report.not_included.len(),
+        label,
+        report.failed
+    );
+
+    Ok(())
+}
+
+/// As ERC20 transaction type, produce ERC-20 token traffic between the new accounts in 3 phases:
+/// 1. deploy: the token at `token_address` is reused, otherwise a new one is deployed by the
+///    `deployer`. Either way, the `deployer` must be the token's owner, the only one allowed to
+///    mint.
+/// 2. mint: the `deployer` mints `amount * TXS_PER_ACCOUNT` tokens to each account, which then
+///    approves the next account to spend all of its tokens.
+/// 3. workload: the txs interleave `transfer` to the next account, `approve` of the next account &
+///    `transferFrom` the previous account to itself (as approved up front in the mint phase). So,
+///    at least 3 txs (4 w/ 2 accounts) are needed to make all of them.
+///
+/// Each phase's txs are sent in batches of `MAX_BATCH_SIZE` w/o waiting on confirmation, but a
+/// phase only starts once all the txs of the previous one are included.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn multicall_erc20_txs(
+    client: Arc<Client>,
+    token_address: Option<Address>,
+    deployer: Wallet<SigningKey>,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    amount: u64,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let amount = U256::from(amount);
+    let num_signers = senders.signers.len();
+    let ops: HashSet<Erc20Op> =
+        (0..senders.num_txs()).map(|tx| Erc20Op::of_tx(tx, num_signers)).collect();
+    eyre::ensure!(
+        ops.len() == 3,
+        "ERC20 txs need enough txs (accounts × txs per account) to make transfer, approve & \
+         transferFrom, got {}",
+        senders.num_txs()
+    );
+
+    // deploy phase
+    let token_address = match token_address {
+        Some(token_address) => {
+            println!("Reusing ERC-20 token at {:?}", token_address);
+            token_address
+        }
+        None => {
+            let token_address =
+                erc20_deploy(client.clone(), deployer.clone(), senders.chain_id, &senders.fees)
+                    .await?;
+            println!("Deployed ERC-20 token at {:?}", token_address);
+            token_address
+        }
+    };
+    let owner = erc20_owner(client.clone(), token_address).await?;
+    eyre::ensure!(
+        owner == deployer.address(),
+        "ERC-20 token at {:?} is owned by {:?}, so the funder {:?} can't mint",
+        token_address,
+        owner,
+        deployer.address()
+    );
+    let addresses: Vec<Address> = senders.signers.iter().map(|signer| signer.address()).collect();
+    let balance_before =
+        get_total_token_balance(client.clone(), token_address, &addresses, max_batch_size).await?;
+
+    // mint phase: the deployer sends a mint to each account, then each account its approval
+    let mint_amount = amount * senders.txs_per_account;
+    let minter = Senders::new(
+        client.clone(),
+        vec![deployer],
+        senders.chain_id,
+        senders.fees.clone(),
+        num_signers as u32,
+        max_batch_size,
+    )
+    .await?;
+    let setup_txs: Vec<usize> = (0..num_signers).collect();
+    send_erc20_ops(
+        client.clone(),
+        token_address,
+        &minter,
+        &setup_txs,
+        |tx| Erc20Op::Mint(addresses[tx]),
+        mint_amount,
+        max_batch_size,
+        inclusion_timeout,
+        "ERC20::mint()",
+    )
+    .await?;
+    send_erc20_ops(
+        client.clone(),
+        token_address,
+        &senders,
+        &setup_txs,
+        |_| Erc20Op::Approve,
+        mint_amount,
+        max_batch_size,
+        inclusion_timeout,
+        "ERC20::approve()",
+    )
+    .await?;
+
+    // workload phase
+    let txs: Vec<usize> = (0..senders.num_txs()).collect();
+    send_erc20_ops(
+        client.clone(),
+        token_address,
+        &senders,
+        &txs,
+        |tx| Erc20Op::of_tx(tx, num_signers),
+        amount,
+        max_batch_size,
+        inclusion_timeout,
+        "ERC20::transfer()/approve()/transferFrom()",
+    )
+    .await?;
+
+    // tokens only move between the accounts, so their total is what they minted
+    let balance_after =
+        get_total_token_balance(client.clone(), token_address, &addresses, max_batch_size).await?;
+    let minted = mint_amount * num_signers;
+    println!(
+        "Token balance of the accounts: {} (before: {}, minted: {})",
+        format_ether(balance_after),
+        format_ether(balance_before),
+        format_ether(minted)
+    );
+    eyre::ensure!(
+        balance_after == balance_before + minted,
+        "Token balance of the accounts doesn't add up, some 'mint' txs must have failed"
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn erc20_op_of_tx() {
+        for num_signers in 1..=7 {
+            let txs_per_account = 3;
+            let num_txs = num_signers * txs_per_account;
+            // the senders go round-robin
+            for account in 0..num_signers {
+                let ops: HashSet<Erc20Op> = (account..num_txs)
+                    .step_by(num_signers)
+                    .map(|tx| Erc20Op::of_tx(tx, num_signers))
+                    .collect();
+                assert_eq!(ops.len(), 3, "account {} of {}", account, num_signers);
+            }
+            if num_signers >= 3 {
+                for tx in 0..num_signers - 2 {
+                    let ops: HashSet<Erc20Op> =
+                        (tx..tx + 3).map(|tx| Erc20Op::of_tx(tx, num_signers)).collect();
+                    assert_eq!(ops.len(), 3, "txs {}.. of {}", tx, num_signers);
+                }
+            }
+        }
+    }
+}
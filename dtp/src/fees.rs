@@ -0,0 +1,390 @@
+use crate::transport::Client;
+use async_trait::async_trait;
+use ethers::{
+    core::k256::ecdsa::SigningKey,
+    prelude::*,
+    signers::Wallet,
+    types::transaction::{eip2718::TypedTransaction, eip2930::Eip2930TransactionRequest},
+    utils::{format_units, parse_units},
+};
+use std::{fmt, str::FromStr, sync::Arc};
+
+/// Envelope (EIP-2718 type) of the txs sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum TxEnvelope {
+    /// Type 0, priced by `gasPrice`
+    Legacy,
+    /// Type 1, priced by `gasPrice`, w/ an access list
+    Eip2930,
+    /// Type 2, priced by `maxFeePerGas` & `maxPriorityFeePerGas`
+    #[default]
+    Eip1559,
+}
+
+/// Implement `FromStr` trait for TxEnvelope
+impl FromStr for TxEnvelope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "legacy" => Ok(TxEnvelope::Legacy),
+            "eip2930" => Ok(TxEnvelope::Eip2930),
+            "eip1559" => Ok(TxEnvelope::Eip1559),
+            _ => Err(format!("\'{}\' is not a valid TxEnvelope", s)),
+        }
+    }
+}
+
+/// Parse an amount in Gwei (e.g. `3.5`) into Wei.
+pub(crate) fn parse_gwei(s: &str) -> Result<U256, String> {
+    parse_units(s.trim(), "gwei")
+        .map(U256::from)
+        .map_err(|e| format!("\'{}\' is not a valid amount in Gwei: {}", s, e))
+}
+
+/// Convert Wei to Gwei (in String)
+pub(crate) fn wei_to_gwei_string(wei: U256) -> String {
+    format_units(wei, "gwei").unwrap()
+}
+
+/// Envelope & fees of the txs. The fees left unset are filled per tx by the node: its gas price
+/// for legacy & EIP-2930 txs, or the fees estimated from its fee history for EIP-1559 txs.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TxFees {
+    pub(crate) envelope: TxEnvelope,
+    /// Gas price of legacy & EIP-2930 txs
+    pub(crate) gas_price: Option<U256>,
+    /// Max. fee per gas of EIP-1559 txs
+    pub(crate) max_fee_per_gas: Option<U256>,
+    /// Max. priority fee per gas of EIP-1559 txs
+    pub(crate) max_priority_fee_per_gas: Option<U256>,
+}
+
+impl fmt::Display for TxFees {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gwei = |wei: Option<U256>| match wei {
+            Some(wei) => format!("{} Gwei", wei_to_gwei_string(wei)),
+            None => "from the node".to_string(),
+        };
+        match self.envelope {
+            TxEnvelope::Legacy | TxEnvelope::Eip2930 => {
+                write!(f, "{:?} txs, gas price: {}", self.envelope, gwei(self.gas_price))
+            }
+            TxEnvelope::Eip1559 => write!(
+                f,
+                "{:?} txs, max. fee per gas: {}, max. priority fee per gas: {}",
+                self.envelope,
+                gwei(self.max_fee_per_gas),
+                gwei(self.max_priority_fee_per_gas)
+            ),
+        }
+    }
+}
+
+impl TxFees {
+    /// Convert `tx` into the envelope, keeping its fields (incl. its access list, if any), and
+    /// set the fees. Unset fees are kept from `tx`, if it already has the envelope.
+    pub(crate) fn apply(&self, tx: &mut TypedTransaction) {
+        let access_list = tx.access_list().cloned().unwrap_or_default();
+        let mut request = TransactionRequest::new();
+        request.from = tx.from().copied();
+        request.to = tx.to().cloned();
+        request.gas = tx.gas().copied();
+        request.value = tx.value().copied();
+        request.data = tx.data().cloned();
+        request.nonce = tx.nonce().copied();
+        request.chain_id = tx.chain_id();
+
+        *tx = match self.envelope {
+            TxEnvelope::Legacy => {
+                request.gas_price = self.gas_price.or(match tx {
+                    TypedTransaction::Legacy(inner) => inner.gas_price,
+                    _ => None,
+                });
+                request.into()
+            }
+            TxEnvelope::Eip2930 => {
+                request.gas_price = self.gas_price.or(match tx {
+                    TypedTransaction::Eip2930(inner) => inner.tx.gas_price,
+                    _ => None,
+                });
+                Eip2930TransactionRequest::new(request, access_list).into()
+            }
+            TxEnvelope::Eip1559 => {
+                let (max_fee_per_gas, max_priority_fee_per_gas) = match tx {
+                    TypedTransaction::Eip1559(inner) => {
+                        (inner.max_fee_per_gas, inner.max_priority_fee_per_gas)
+                    }
+                    _ => (None, None),
+                };
+                Eip1559TransactionRequest {
+                    from: request.from,
+                    to: request.to,
+                    gas: request.gas,
+                    value: request.value,
+                    data: request.data,
+                    nonce: request.nonce,
+                    access_list,
+                    max_priority_fee_per_gas: self
+                        .max_priority_fee_per_gas
+                        .or(max_priority_fee_per_gas),
+                    max_fee_per_gas: self.max_fee_per_gas.or(max_fee_per_gas),
+                    chain_id: request.chain_id,
+                }
+                .into()
+            }
+        };
+    }
+
+    /// Fees w/ the unset ones filled by the node, for txs built once (e.g. pre-signed) rather than
+    /// filled per tx.
+    pub(crate) async fn resolve(&self, client: &Client) -> eyre::Result<TxFees> {
+        let mut fees = self.clone();
+        match self.envelope {
+            TxEnvelope::Legacy | TxEnvelope::Eip2930 => {
+                if fees.gas_price.is_none() {
+                    fees.gas_price = Some(client.get_gas_price().await?);
+                }
+            }
+            TxEnvelope::Eip1559 => {
+                if fees.max_fee_per_gas.is_none() || fees.max_priority_fee_per_gas.is_none() {
+                    let (max_fee_per_gas, max_priority_fee_per_gas) =
+                        client.estimate_eip1559_fees(None).await?;
+                    let max_priority_fee_per_gas =
+                        *fees.max_priority_fee_per_gas.get_or_insert(max_priority_fee_per_gas);
+                    fees.max_fee_per_gas
+                        .get_or_insert(max_fee_per_gas.max(max_priority_fee_per_gas));
+                }
+            }
+        }
+
+        Ok(fees)
+    }
+
+    /// Fees resolved as per `resolve`, for txs signed long before they're sent (e.g. over a long
+    /// load profile), while the base fee may rise. Unless set, the max. fee per gas of EIP-1559
+    /// txs thus covers a base fee of up to `headroom` times the current one.
+    pub(crate) async fn resolve_w_headroom(
+        &self,
+        client: &Client,
+        headroom: u64,
+    ) -> eyre::Result<TxFees> {
+        let mut fees = self.resolve(client).await?;
+        if fees.envelope == TxEnvelope::Eip1559 && self.max_fee_per_gas.is_none() {
+            let block = client
+                .get_block(BlockNumber::Latest)
+                .await?
+                .ok_or_else(|| eyre::eyre!("Failed to get the latest block"))?;
+            if let Some(base_fee) = block.base_fee_per_gas {
+                let max_priority_fee_per_gas =
+                    fees.max_priority_fee_per_gas.expect("resolved above");
+                fees.max_fee_per_gas =
+                    fees.max_fee_per_gas.max(Some(base_fee * headroom + max_priority_fee_per_gas));
+            }
+        }
+
+        Ok(fees)
+    }
+
+    /// Max. price per gas a tx may pay, e.g. to check that a sender can afford its txs.
+    pub(crate) async fn max_gas_price(&self, client: &Client) -> eyre::Result<U256> {
+        let fees = self.resolve(client).await?;
+        let max_gas_price = match fees.envelope {
+            TxEnvelope::Legacy | TxEnvelope::Eip2930 => fees.gas_price,
+            TxEnvelope::Eip1559 => fees.max_fee_per_gas,
+        };
+
+        Ok(max_gas_price.expect("resolved above"))
+    }
+}
+
+/// Middleware converting each tx sent into the envelope w/ the `fees`, before the inner
+/// middleware fills the rest of it (e.g. the fees left unset, the gas).
+#[derive(Debug)]
+pub(crate) struct FeeMiddleware<M> {
+    inner: M,
+    fees: TxFees,
+}
+
+/// Error of the inner middleware of a `FeeMiddleware`, as it adds no error of its own.
+#[derive(Debug)]
+pub(crate) struct FeeMiddlewareError<M: Middleware>(M::Error);
+
+impl<M: Middleware> fmt::Display for FeeMiddlewareError<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<M: Middleware> std::error::Error for FeeMiddlewareError<M> {}
+
+impl<M: Middleware> MiddlewareError for FeeMiddlewareError<M> {
+    type Inner = M::Error;
+
+    fn from_err(e: Self::Inner) -> Self {
+        FeeMiddlewareError(e)
+    }
+
+    fn as_inner(&self) -> Option<&Self::Inner> {
+        Some(&self.0)
+    }
+}
+
+#[async_trait]
+impl<M: Middleware> Middleware for FeeMiddleware<M> {
+    type Error = FeeMiddlewareError<M>;
+    type Provider = M::Provider;
+    type Inner = M;
+
+    fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    async fn fill_transaction(
+        &self,
+        tx: &mut TypedTransaction,
+        block: Option<BlockId>,
+    ) -> Result<(), Self::Error> {
+        self.fees.apply(tx);
+        self.inner.fill_transaction(tx, block).await.map_err(FeeMiddlewareError)
+    }
+}
+
+/// Middleware signing the txs of `signer` for `chain_id`, w/ the envelope & `fees`.
+pub(crate) fn signer_middleware(
+    client: Arc<Client>,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+) -> SignerMiddleware<FeeMiddleware<Arc<Client>>, Wallet<SigningKey>> {
+    let fee_middleware = FeeMiddleware { inner: client, fees: fees.clone() };
+
+    SignerMiddleware::new(fee_middleware, signer.with_chain_id(chain_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::types::transaction::eip2930::{AccessList, AccessListItem};
+
+    fn access_list() -> AccessList {
+        AccessList(vec![AccessListItem {
+            address: Address::repeat_byte(0xaa),
+            storage_keys: vec![H256::repeat_byte(0x01)],
+        }])
+    }
+
+    /// A tx of each envelope w/ the same fields, priced at 7 Wei.
+    fn txs() -> Vec<TypedTransaction> {
+        let request = TransactionRequest::new()
+            .from(Address::repeat_byte(0x01))
+            .to(Address::repeat_byte(0x02))
+            .gas(21_000)
+            .value(5)
+            .data(vec![0xde, 0xad])
+            .nonce(3)
+            .chain_id(1);
+        vec![
+            request.clone().gas_price(7).into(),
+            Eip2930TransactionRequest::new(request.clone().gas_price(7), access_list()).into(),
+            Eip1559TransactionRequest::new()
+                .from(Address::repeat_byte(0x01))
+                .to(Address::repeat_byte(0x02))
+                .gas(21_000)
+                .value(5)
+                .data(vec![0xde, 0xad])
+                .nonce(3)
+                .chain_id(1)
+                .access_list(access_list())
+                .max_fee_per_gas(7)
+                .max_priority_fee_per_gas(7)
+                .into(),
+        ]
+    }
+
+    fn fees(envelope: TxEnvelope, fee: Option<u64>) -> TxFees {
+        let fee = fee.map(U256::from);
+        TxFees {
+            envelope,
+            gas_price: fee,
+            max_fee_per_gas: fee,
+            max_priority_fee_per_gas: fee.map(|fee| fee / 2),
+        }
+    }
+
+    /// The fields other than the envelope & fees are kept.
+    fn assert_fields_kept(tx: &TypedTransaction) {
+        assert_eq!(tx.from(), Some(&Address::repeat_byte(0x01)));
+        assert_eq!(tx.to_addr(), Some(&Address::repeat_byte(0x02)));
+        assert_eq!(tx.gas(), Some(&U256::from(21_000)));
+        assert_eq!(tx.value(), Some(&U256::from(5)));
+        assert_eq!(tx.data(), Some(&Bytes::from(vec![0xde, 0xad])));
+        assert_eq!(tx.nonce(), Some(&U256::from(3)));
+        assert_eq!(tx.chain_id(), Some(U64::one()));
+    }
+
+    #[test]
+    fn apply_converts_into_legacy() {
+        for (i, mut tx) in txs().into_iter().enumerate() {
+            fees(TxEnvelope::Legacy, Some(10)).apply(&mut tx);
+            assert!(matches!(tx, TypedTransaction::Legacy(_)));
+            assert_fields_kept(&tx);
+            assert_eq!(tx.gas_price(), Some(10.into()));
+
+            // w/o a gas price set, only a legacy tx keeps its own
+            let mut tx = txs().swap_remove(i);
+            fees(TxEnvelope::Legacy, None).apply(&mut tx);
+            assert_eq!(tx.gas_price(), (i == 0).then(|| 7.into()));
+        }
+    }
+
+    #[test]
+    fn apply_converts_into_eip2930() {
+        for (i, mut tx) in txs().into_iter().enumerate() {
+            let had_access_list = tx.access_list().is_some();
+            fees(TxEnvelope::Eip2930, Some(10)).apply(&mut tx);
+            assert!(matches!(tx, TypedTransaction::Eip2930(_)));
+            assert_fields_kept(&tx);
+            assert_eq!(tx.gas_price(), Some(10.into()));
+            match had_access_list {
+                true => assert_eq!(tx.access_list(), Some(&access_list())),
+                false => assert_eq!(tx.access_list(), Some(&AccessList::default())),
+            }
+
+            // w/o a gas price set, only an EIP-2930 tx keeps its own
+            let mut tx = txs().swap_remove(i);
+            fees(TxEnvelope::Eip2930, None).apply(&mut tx);
+            assert_eq!(tx.gas_price(), (i == 1).then(|| 7.into()));
+        }
+    }
+
+    #[test]
+    fn apply_converts_into_eip1559() {
+        for (i, mut tx) in txs().into_iter().enumerate() {
+            fees(TxEnvelope::Eip1559, Some(10)).apply(&mut tx);
+            assert_fields_kept(&tx);
+            match &tx {
+                TypedTransaction::Eip1559(inner) => {
+                    assert_eq!(inner.max_fee_per_gas, Some(10.into()));
+                    assert_eq!(inner.max_priority_fee_per_gas, Some(5.into()));
+                }
+                _ => panic!("not converted into EIP-1559: {:?}", tx),
+            }
+            if i > 0 {
+                assert_eq!(tx.access_list(), Some(&access_list()));
+            }
+
+            // w/o fees set, only an EIP-1559 tx keeps its own
+            let mut tx = txs().swap_remove(i);
+            fees(TxEnvelope::Eip1559, None).apply(&mut tx);
+            let kept = (i == 2).then(|| U256::from(7));
+            match &tx {
+                TypedTransaction::Eip1559(inner) => {
+                    assert_eq!(inner.max_fee_per_gas, kept);
+                    assert_eq!(inner.max_priority_fee_per_gas, kept);
+                }
+                _ => panic!("not converted into EIP-1559: {:?}", tx),
+            }
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use crate::{
+    contracts::fund_transfer_tssc_to_many_revert,
+    fees::{signer_middleware, TxFees},
+    transfer::TRANSFER_GAS,
+    transport::Client,
+    utils::{track_inclusion, transfer_tssc_bulk, Senders},
+};
+use bindings::fund::{FundErrors, InsufficientFundsInContract, InvalidReceiverAddress};
+use ethers::{
+    core::{k256::ecdsa::SigningKey, rand::thread_rng},
+    prelude::*,
+    signers::Wallet,
+};
+use futures::future::join_all;
+use std::{collections::BTreeMap, str::FromStr, sync::Arc, time::Duration};
+
+/// Gas limit of the `transferTsscToMany` txs expected to revert. It's not estimated, as the
+/// estimation fails on the revert.
+const FUND_REVERT_GAS: u64 = 100_000;
+
+/// Case of the INVALID txs, each designed to revert or be rejected by the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum InvalidCase {
+    /// `transferTsscToMany` w/o value, reverting w/ `InsufficientFundsInContract`.
+    ///
+    /// NOTE: No case reverts w/ `ZeroTSSC`, as it's unreachable in the deployed `Fund`: its only
+    /// entry point `transferTsscToMany` reverts w/ `InsufficientFundsInContract` on a zero amount
+    /// per receiver (`msg.value / receivers`), before the per-transfer `ZeroTSSC` check. W/o any
+    /// receiver, the division panics instead.
+    ZeroValue,
+    /// `transferTsscToMany` to the zero address, reverting w/ `InvalidReceiverAddress`.
+    ZeroAddress,
+    /// `transferTsscToMany` to the sender itself, reverting w/ `InvalidReceiverAddress`.
+    SelfReceiver,
+    /// A transfer w/ a gas limit below the intrinsic gas, rejected by the node.
+    LowGas,
+    /// A transfer signed for another chain id, rejected by the node.
+    BadChainId,
+    /// A transfer from a fresh (unfunded) account, rejected by the node.
+    InsufficientBalance,
+    /// All of the above cases.
+    All,
+}
+
+/// Implement `FromStr` trait for InvalidCase
+impl FromStr for InvalidCase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "zero-value" => Ok(InvalidCase::ZeroValue),
+            "zero-address" => Ok(InvalidCase::ZeroAddress),
+            "self-receiver" => Ok(InvalidCase::SelfReceiver),
+            "low-gas" => Ok(InvalidCase::LowGas),
+            "bad-chain-id" => Ok(InvalidCase::BadChainId),
+            "insufficient-balance" => Ok(InvalidCase::InsufficientBalance),
+            "all" => Ok(InvalidCase::All),
+            _ => Err(format!("\'{}\' is not a valid InvalidCase", s)),
+        }
+    }
+}
+
+/// Expected outcome of an INVALID tx.
+#[derive(Debug)]
+enum Expected {
+    /// The call reverts w/ this `Fund` error, and so does the tx once included.
+    Revert(FundErrors),
+    /// The node rejects the tx w/ an error containing any of these (lowercase) messages.
+    Rejected(&'static [&'static str]),
+}
+
+impl InvalidCase {
+    /// Cases run for `All`.
+    const ALL: [InvalidCase; 6] = [
+        InvalidCase::ZeroValue,
+        InvalidCase::ZeroAddress,
+        InvalidCase::SelfReceiver,
+        InvalidCase::LowGas,
+        InvalidCase::BadChainId,
+        InvalidCase::InsufficientBalance,
+    ];
+
+    /// Distinct cases to run, w/ `All` expanded.
+    fn expand(cases: &[InvalidCase]) -> Vec<InvalidCase> {
+        let mut expanded = Vec::new();
+        for case in cases {
+            match case {
+                InvalidCase::All => expanded.extend(Self::ALL),
+                case => expanded.push(*case),
+            }
+        }
+        expanded.sort_unstable();
+        expanded.dedup();
+
+        expanded
+    }
+
+    fn expected(self) -> Expected {
+        match self {
+            InvalidCase::ZeroValue => Expected::Revert(FundErrors::InsufficientFundsInContract(
+                InsufficientFundsInContract,
+            )),
+            InvalidCase::ZeroAddress | InvalidCase::SelfReceiver => {
+                Expected::Revert(FundErrors::InvalidReceiverAddress(InvalidReceiverAddress))
+            }
+            InvalidCase::LowGas => Expected::Rejected(&["intrinsic gas", "gas too low"]),
+            InvalidCase::BadChainId => {
+                Expected::Rejected(&["chain id", "chainid", "invalid sender", "invalid signature"])
+            }
+            InvalidCase::InsufficientBalance => {
+                Expected::Rejected(&["insufficient funds", "insufficient balance"])
+            }
+            InvalidCase::All => unreachable!("expanded into the cases"),
+        }
+    }
+}
+
+/// Outcome of an INVALID tx, before its receipt (if sent) is checked.
+#[derive(Debug)]
+enum Outcome {
+    /// The tx got rejected as expected.
+    Rejected,
+    /// The call reverted as expected in simulation, and the tx was sent to revert on-chain too.
+    Sent(TxHash),
+    /// The tx didn't fail as expected.
+    Mismatch(String),
+}
+
+/// Pass/fail of the INVALID txs of a case.
+#[derive(Debug, Default)]
+struct CaseStats {
+    passed: usize,
+    /// Mismatches w/ the expected outcome.
+    failed: Vec<String>,
+}
+
+/// send a transfer of 1 Wei to `to` with the given `gas` limit & `nonce`, signed for `chain_id`,
+/// w/o waiting for its receipt
+/// NOTE: signer needed as it incurs gas fees.
+async fn transfer_with_gas_send(
+    client: Arc<Client>,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    to: Address,
+    gas: u64,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client, signer, chain_id, fees);
+
+    let tx = TransactionRequest::pay(to, 1).gas(gas).nonce(nonce);
+    let tx_hash = client_middleware.send_transaction(tx, None).await?.tx_hash();
+
+    Ok(tx_hash)
+}
+
+/// Send the `tx`-th tx as per `case`, and check whether it fails as expected.
+async fn send_invalid_tx(
+    client: Arc<Client>,
+    fund_address: Address,
+    senders: Arc<Senders>,
+    tx: usize,
+    case: InvalidCase,
+) -> eyre::Result<Outcome> {
+    let signer = senders.signer(tx).to_owned();
+    let next = senders.signer(tx + 1).address();
+    let chain_id = senders.chain_id;
+    let fees = &senders.fees;
+
+    match case.expected() {
+        Expected::Revert(expected) => {
+            let (tos, amount) = match case {
+                InvalidCase::ZeroValue => (vec![Address::random()], U256::zero()),
+                InvalidCase::ZeroAddress => (vec![Address::zero()], U256::one()),
+                _ => (vec![signer.address()], U256::one()),
+            };
+
+            // the call must revert w/ the expected error in simulation first
+            let value = amount * tos.len();
+            match fund_transfer_tssc_to_many_revert(
+                client.clone(),
+                fund_address,
+                signer.address(),
+                tos.clone(),
+                value,
+            )
+            .await
+            {
+                Ok(Some(fund_error)) if fund_error == expected => {}
+                Ok(Some(fund_error)) => {
+                    return Ok(Outcome::Mismatch(format!("call reverted w/ {:?}", fund_error)))
+                }
+                Ok(None) => return Ok(Outcome::Mismatch("call succeeded".to_string())),
+                Err(err) => return Ok(Outcome::Mismatch(format!("call failed: {}", err))),
+            }
+
+            let res = senders
+                .nonces
+                .send(client.as_ref(), signer.address(), |nonce| {
+                    transfer_tssc_bulk(
+                        client.clone(),
+                        &signer,
+                        tos.clone(),
+                        amount,
+                        fund_address,
+                        chain_id,
+                        fees,
+                        nonce,
+                        FUND_REVERT_GAS.into(),
+                    )
+                })
+                .await;
+            Ok(match res {
+                Ok(tx_hash) => Outcome::Sent(tx_hash),
+                Err(err) => Outcome::Mismatch(format!("tx rejected: {}", err)),
+            })
+        }
+        Expected::Rejected(messages) => {
+            let res = match case {
+                InvalidCase::LowGas | InvalidCase::BadChainId => {
+                    let (chain_id, gas) = match case {
+                        InvalidCase::LowGas => (chain_id, TRANSFER_GAS - 1),
+                        _ => (chain_id + 1, TRANSFER_GAS),
+                    };
+                    senders
+                        .nonces
+                        .send(client.as_ref(), signer.address(), |nonce| {
+                            transfer_with_gas_send(
+                                client.clone(),
+                                signer.clone(),
+                                chain_id,
+                                fees,
+                                next,
+                                gas,
+                                nonce,
+                            )
+                        })
+                        .await
+                }
+                _ => {
+                    // a fresh account has no funds (nor a managed nonce)
+                    let fresh = LocalWallet::new(&mut thread_rng());
+                    transfer_with_gas_send(
+                        client.clone(),
+                        fresh,
+                        chain_id,
+                        fees,
+                        next,
+                        TRANSFER_GAS,
+                        U256::zero(),
+                    )
+                    .await
+                }
+            };
+            Ok(match res {
+                Ok(tx_hash) => Outcome::Mismatch(format!("tx accepted: {:?}", tx_hash)),
+                Err(err) => {
+                    let msg = err.to_string().to_lowercase();
+                    if messages.iter().any(|message| msg.contains(message)) {
+                        Outcome::Rejected
+                    } else {
+                        Outcome::Mismatch(format!("tx rejected w/ an unexpected error: {}", err))
+                    }
+                }
+            })
+        }
+    }
+}
+
+/// As INVALID transaction type, the txs of the new accounts are designed to revert (w/ `Fund`
+/// errors) or to be rejected by the node, as per the `cases`. The txs are split evenly across the
+/// cases, run one after another in batches of `MAX_BATCH_SIZE`.
+///
+/// Each tx is checked against its case's expected outcome: a reverting call must revert w/ the
+/// expected error in simulation, then its tx must be included & revert on-chain, while a rejected
+/// tx's error must match the expected one. The nonce of any rejected tx is handed back for the
+/// next tx of its sender. Fails if any tx doesn't fail as expected, so that it can be
+/// used to regression-test the rejection handling.
+pub(crate) async fn multicall_invalid_txs(
+    client: Arc<Client>,
+    fund_address: Address,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    cases: &[InvalidCase],
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let cases = InvalidCase::expand(cases);
+    let num_txs = senders.num_txs();
+    eyre::ensure!(
+        !cases.is_empty() && num_txs >= cases.len(),
+        "{} txs can't be split across {} cases",
+        num_txs,
+        cases.len()
+    );
+
+    let mut stats: BTreeMap<InvalidCase, CaseStats> = BTreeMap::new();
+    let mut sent = Vec::new();
+    let ((), report) =
+        track_inclusion(client.clone(), inclusion_timeout, "invalid", async |tracker| {
+            for (i, &case) in cases.iter().enumerate() {
+                let case_stats = stats.entry(case).or_default();
+                let txs: Vec<usize> =
+                    (i * num_txs / cases.len()..(i + 1) * num_txs / cases.len()).collect();
+                for chunk in txs.chunks(max_batch_size.into()) {
+                    let batch = chunk.iter().map(|&tx| {
+                        send_invalid_tx(client.clone(), fund_address, senders.clone(), tx, case)
+                    });
+
+                    for outcome in join_all(batch).await {
+                        match outcome? {
+                            Outcome::Rejected => case_stats.passed += 1,
+                            Outcome::Sent(tx_hash) => {
+                                tracker.track(tx_hash);
+                                sent.push((tx_hash, case));
+                            }
+                            Outcome::Mismatch(mismatch) => case_stats.failed.push(mismatch),
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+        .await?;
+
+    // the sent txs must be included & revert
+    for (tx_hash, case) in sent {
+        let case_stats = stats.entry(case).or_default();
+        match report.receipts.get(&tx_hash) {
+            Some(tx_receipt) if tx_receipt.status == Some(U64::zero()) => case_stats.passed += 1,
+            Some(_) => case_stats.failed.push(format!("tx {:?} succeeded", tx_hash)),
+            None => case_stats.failed.push(format!("tx {:?} not included", tx_hash)),
+        }
+    }
+
+    println!("=====\n{:<22} {:<60} {:>7} {:>7}", "case", "expected", "passed", "failed");
+    for (case, case_stats) in stats.iter() {
+        let expected = match case.expected() {
+            Expected::Revert(fund_error) => format!("revert w/ {}", fund_error),
+            Expected::Rejected(messages) => format!("rejected w/ \'{}\'", messages.join("\'|\'")),
+        };
+        println!(
+            "{:<22} {:<60} {:>7} {:>7}",
+            format!("{:?}", case),
+            expected,
+            case_stats.passed,
+            case_stats.failed.len()
+        );
+    }
+    let mut num_failed = 0;
+    for (case, case_stats) in stats.iter() {
+        for mismatch in case_stats.failed.iter() {
+            println!("{:?} failed: {}", case, mismatch);
+        }
+        num_failed += case_stats.failed.len();
+    }
+    eyre::ensure!(num_failed == 0, "{} invalid txs didn't fail as expected", num_failed);
+
+    Ok(())
+}
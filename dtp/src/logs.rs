@@ -0,0 +1,192 @@
+use crate::{
+    scheduler::get_block_gas_limit,
+    transport::Client,
+    utils::{estimate_transfer_tssc_bulk, run_tracked, transfer_tssc_bulk, Senders},
+};
+use ethers::prelude::*;
+use futures::future::join_all;
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+/// Margin (in %) added over the estimated gas of each LOGS tx.
+const LOGS_GAS_MARGIN_PERCENT: u64 = 10;
+
+/// No. of bits of a logs bloom filter.
+const BLOOM_BITS: u32 = 2048;
+
+/// Logs & bloom of a block w/ LOGS txs.
+#[derive(Debug, Default)]
+struct BlockStats {
+    /// Included LOGS txs.
+    txs: usize,
+    /// Logs emitted by all the txs of the block.
+    logs: usize,
+    /// Logs emitted by the LOGS txs.
+    own_logs: usize,
+    /// Bits set in the block's logs bloom.
+    bloom_bits: u32,
+}
+
+/// As LOGS transaction type, each new account calls `Fund::transferTsscToMany` w/ `fanout` fresh
+/// receivers of `dust` Wei each, in batches of `MAX_BATCH_SIZE`. As each receiver emits a
+/// `Transfer` event, a tx emits `fanout` logs. The gas is estimated once for all the txs.
+///
+/// The logs per block, the saturation of the blocks' logs bloom and the size (in bytes) of the
+/// receipts are reported.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn multicall_logs_txs(
+    client: Arc<Client>,
+    fund_address: Address,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    fanout: usize,
+    dust: u64,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let dust = U256::from(dust);
+    let chain_id = senders.chain_id;
+    let fees = &senders.fees;
+
+    // estimate the gas of a tx once, as all of them have the same no. of receivers
+    let estimated_gas = estimate_transfer_tssc_bulk(
+        client.clone(),
+        senders.signer(0),
+        (0..fanout).map(|_| Address::random()).collect(),
+        dust,
+        fund_address,
+        chain_id,
+        fees,
+    )
+    .await?;
+    let gas = estimated_gas * (100 + LOGS_GAS_MARGIN_PERCENT) / 100_u64;
+    let block_gas_limit = get_block_gas_limit(client.clone()).await?;
+    eyre::ensure!(
+        gas <= block_gas_limit,
+        "A \'transferTsscToMany\' tx to {} receivers (~{} gas) doesn't fit in the block gas limit of {}",
+        fanout,
+        gas,
+        block_gas_limit
+    );
+    println!(
+        "Each \'transferTsscToMany\' tx sends {} Wei to {} receivers, emitting {} logs (~{} gas, up to {} txs per block by gas)...",
+        dust,
+        fanout,
+        fanout,
+        gas,
+        block_gas_limit / gas
+    );
+
+    let txs: Vec<usize> = (0..senders.num_txs()).collect();
+    let (_, report) = run_tracked(
+        client.clone(),
+        &senders,
+        &txs,
+        max_batch_size,
+        inclusion_timeout,
+        "Fund::transferTsscToMany()",
+        |_, signer, nonce| {
+            let tos: Vec<Address> = (0..fanout).map(|_| Address::random()).collect();
+            let client = client.clone();
+            async move {
+                transfer_tssc_bulk(
+                    client,
+                    &signer,
+                    tos,
+                    dust,
+                    fund_address,
+                    chain_id,
+                    fees,
+                    nonce,
+                    gas,
+                )
+                .await
+            }
+        },
+    )
+    .await?;
+
+    // the receipts of the included txs, measuring their size as serialized in JSON (incl. the
+    // fields unknown to ethers, kept as is)
+    let mut blocks: BTreeMap<U64, BlockStats> = BTreeMap::new();
+    let (mut reverted, mut gas_used) = (0_usize, U256::zero());
+    let mut receipt_sizes = Vec::with_capacity(report.receipts.len());
+    for (tx_hash, tx_receipt) in report.receipts.iter() {
+        receipt_sizes.push(serde_json::to_string(tx_receipt)?.len());
+
+        let block_stats = blocks.entry(report.included[tx_hash].block_number).or_default();
+        block_stats.txs += 1;
+        block_stats.own_logs += tx_receipt.logs.len();
+        gas_used += tx_receipt.gas_used.unwrap_or_default();
+        if tx_receipt.status != Some(U64::one()) {
+            println!("\'transferTsscToMany\' tx reverted: {:?}", tx_hash);
+            reverted += 1;
+        }
+    }
+
+    // count all the logs of the blocks w/ LOGS txs, and the bits set in their logs bloom
+    let block_numbers: Vec<U64> = blocks.keys().copied().collect();
+    for chunk in block_numbers.chunks(max_batch_size.into()) {
+        let batch = chunk.iter().map(|block_number| {
+            let client = client.clone();
+            async move {
+                let block = client
+                    .get_block(*block_number)
+                    .await?
+                    .ok_or_else(|| eyre::eyre!("Failed to get block #{}", block_number))?;
+                let logs = client
+                    .get_logs(&Filter::new().from_block(*block_number).to_block(*block_number))
+                    .await?;
+                Ok::<_, eyre::Report>((block, logs.len()))
+            }
+        });
+        for (block_number, res) in chunk.iter().zip(join_all(batch).await) {
+            let (block, logs) = res?;
+            let block_stats = blocks.get_mut(block_number).expect("block of an included tx");
+            block_stats.logs = logs;
+            block_stats.bloom_bits = block
+                .logs_bloom
+                .map(|bloom| bloom.as_bytes().iter().map(|byte| byte.count_ones()).sum())
+                .unwrap_or_default();
+        }
+    }
+
+    println!(
+        "=====\n{:>10} {:>6} {:>8} {:>10} {:>11} {:>10}",
+        "block", "txs", "logs", "own logs", "bloom bits", "bloom (%)"
+    );
+    for (block_number, block_stats) in blocks.iter() {
+        println!(
+            "{:>10} {:>6} {:>8} {:>10} {:>11} {:>10.2}",
+            block_number,
+            block_stats.txs,
+            block_stats.logs,
+            block_stats.own_logs,
+            block_stats.bloom_bits,
+            block_stats.bloom_bits as f64 * 100.0 / BLOOM_BITS as f64
+        );
+    }
+
+    let num_blocks = blocks.len().max(1);
+    let saturated =
+        blocks.values().filter(|block_stats| block_stats.bloom_bits == BLOOM_BITS).count();
+    println!(
+        "Logs per block: avg. {}, max. {}. Bloom saturation: avg. {:.2} %, max. {:.2} % ({} fully saturated block(s))",
+        blocks.values().map(|block_stats| block_stats.logs).sum::<usize>() / num_blocks,
+        blocks.values().map(|block_stats| block_stats.logs).max().unwrap_or_default(),
+        blocks.values().map(|block_stats| block_stats.bloom_bits as f64).sum::<f64>() * 100.0
+            / (BLOOM_BITS as f64 * num_blocks as f64),
+        blocks.values().map(|block_stats| block_stats.bloom_bits).max().unwrap_or_default() as f64
+            * 100.0
+            / BLOOM_BITS as f64,
+        saturated
+    );
+    println!(
+        "Receipt size (bytes): avg. {}, max. {}, total {}. \'transferTsscToMany\' txs: {} reverted, avg. gas used: {}",
+        receipt_sizes.iter().sum::<usize>() / receipt_sizes.len().max(1),
+        receipt_sizes.iter().max().copied().unwrap_or_default(),
+        receipt_sizes.iter().sum::<usize>(),
+        reverted,
+        gas_used / report.receipts.len().max(1)
+    );
+
+    Ok(())
+}
@@ -5,16 +5,96 @@
 // imports
 use ethers::prelude::*;
 use eyre::{bail, Result};
-use std::{str::FromStr, sync::Arc};
+use std::{path::PathBuf, str::FromStr, sync::Arc, time::Duration};
 use structopt::StructOpt;
 
 /// utils
 mod utils;
 use utils::*;
 
+/// local nonce management
+mod nonce;
+
 /// contracts
 mod contracts;
 
+/// block scheduling
+mod scheduler;
+
+/// HTTP/WebSocket transport
+mod transport;
+
+/// retry of transient RPC failures
+mod retry;
+use retry::RetryPolicy;
+
+/// pool of RPC endpoints
+mod pool;
+use pool::PoolStrategy;
+
+/// calldata-heavy workload
+mod calldata;
+
+/// compute-bound workload
+mod compute;
+use compute::{Compute, MAX_FACTORIAL_INPUT};
+
+/// contract deployment workload
+mod deploy;
+use deploy::DeployContract;
+
+/// ERC-20 token workload
+mod erc20;
+
+/// randomized `Counter::setNumber` writes
+mod set_number;
+use set_number::SetNumberValues;
+
+/// weighted mix of tx types
+mod mixed;
+use mixed::Mix;
+
+/// native value transfers
+mod transfer;
+use transfer::TransferTo;
+
+/// inclusion tracking of sent txs
+mod tracker;
+
+/// JSON-RPC batch requests
+mod batch;
+use batch::BatchClient;
+
+/// event-log-heavy workload
+mod logs;
+
+/// negative-path txs, expected to revert or be rejected
+mod invalid;
+use invalid::InvalidCase;
+
+/// ABI-driven calls of any contract
+mod abi_call;
+use abi_call::{AbiCall, ArgGen};
+
+/// single-sender Multicall3 aggregation
+mod aggregate;
+
+/// tx envelopes & fees
+mod fees;
+use fees::{parse_gwei, TxEnvelope, TxFees};
+
+/// EIP-2930 access lists
+mod access_list;
+use access_list::AccessListSource;
+
+/// open-loop submission at a fixed rate, or following a load profile
+mod open_loop;
+use open_loop::LoadProfile;
+
+/// pre-sign & blast pipeline
+mod presign;
+use presign::PresignedCall;
+
 /// TODO: able to parse like "1 ETH", "1000 Wei"
 /// TODO: `transaction_type` can be made as optional in cases where just need to transfer
 /// funds to newly created accounts share their account details with the set of users as pre-funded account.
@@ -26,14 +106,129 @@ struct Cli {
     #[structopt(short = "a", long)]
     num_accounts: u32,
 
-    /// Transaction type: light or heavy
+    /// Transaction type: light, heavy, compute, setnumber, calldata, transfer, erc20, deploy, mixed,
+    /// logs, invalid or abi
     #[structopt(short = "t", long)]
     transaction_type: String,
 
+    /// Number of txs sent by each account, with locally managed nonces
+    #[structopt(long, default_value = "1")]
+    txs_per_account: u32,
+
     /// Number of blocks to run for
     #[structopt(short = "b", long)]
     num_blocks: Option<u32>,
 
+    /// Amount (in Wei) of TSSC sent by each transfer tx
+    #[structopt(long, default_value = "1000000000")]
+    transfer_amount: u64,
+
+    /// Recipients of the transfer txs: signers (each other) or fresh (random addresses)
+    #[structopt(long, default_value = "signers")]
+    transfer_to: TransferTo,
+
+    /// Amount (in the token's smallest unit) of each ERC-20 `transfer`/`transferFrom`. Each
+    /// account is minted this amount times `--txs-per-account`
+    #[structopt(long, default_value = "1000000000000000000")]
+    erc20_amount: u64,
+
+    /// Input of the `Load::factorial` calls made by the compute txs, i.e. the no. of
+    /// multiplications per round (max: 57, beyond which the result overflows)
+    #[structopt(long, default_value = "57")]
+    factorial_input: u64,
+
+    /// No. of `Load::factorial` rounds run by each compute tx (via `Multicall3::aggregate3`),
+    /// before it writes a single slot (`Counter::increment()`)
+    #[structopt(long, default_value = "50")]
+    compute_rounds: u32,
+
+    /// Values written by the setnumber txs: random or account (the sender's address)
+    #[structopt(long, default_value = "random")]
+    set_number_values: SetNumberValues,
+
+    /// Payload sizes (in bytes) of the calldata txs, comma-separated. The txs are split evenly
+    /// across them
+    #[structopt(long, default_value = "32,1024,32768,131072", use_delimiter = true)]
+    calldata_sizes: Vec<usize>,
+
+    /// Sink address (or contract) receiving the calldata txs
+    #[structopt(long, default_value = "0x0000000000000000000000000000000000000000")]
+    calldata_to: Address,
+
+    /// Weights of the tx types drawn by each mixed tx, among light, heavy, compute, setnumber &
+    /// transfer
+    #[structopt(long, default_value = "light=70,heavy=10,transfer=20")]
+    mix: Mix,
+
+    /// No. of receivers of each logs tx, i.e. no. of `Transfer` logs it emits
+    #[structopt(long, default_value = "10")]
+    log_fanout: usize,
+
+    /// Amount (in Wei) of TSSC sent to each receiver of the logs txs
+    #[structopt(long, default_value = "1")]
+    log_dust: u64,
+
+    /// Cases of the invalid txs, comma-separated: zero-value, zero-address, self-receiver, low-gas,
+    /// bad-chain-id, insufficient-balance or all. The txs are split evenly across them
+    #[structopt(long, default_value = "all", use_delimiter = true)]
+    invalid_cases: Vec<InvalidCase>,
+
+    /// Contract called by the abi txs
+    #[structopt(long)]
+    abi_contract: Option<Address>,
+
+    /// ABI JSON file (or artifact w/ an `abi` field) of the contract called by the abi txs
+    #[structopt(long, parse(from_os_str))]
+    abi_file: Option<PathBuf>,
+
+    /// Function called by the abi txs, e.g. `setNumber(uint256)` (or `setNumber` if not overloaded)
+    #[structopt(long)]
+    abi_function: Option<String>,
+
+    /// Generator of each arg of the abi function, in order: `const:<value>`, `random:<min>..<max>`,
+    /// `account` (sender's index) or `seq[:<start>]` (tx's index)
+    #[structopt(long, number_of_values = 1)]
+    abi_arg: Vec<ArgGen>,
+
+    /// Contract deployed by the deploy txs: counter, load, fund, multicall3, erc20 or all (in
+    /// turn)
+    #[structopt(long, default_value = "all")]
+    deploy_contract: DeployContract,
+
+    /// Time (in secs) within which a sent tx must be included, before being flagged as not
+    /// included
+    #[structopt(long, default_value = "120")]
+    inclusion_timeout_secs: u64,
+
+    /// Pre-sign all the txs offline, then blast them via `eth_sendRawTransaction` and report the
+    /// ingest rate separately from the inclusion rate
+    #[structopt(long, conflicts_with = "num-blocks")]
+    presign: bool,
+
+    /// Single-sender approach: the first new account packs all the calls (`--txs-per-account` x
+    /// no. of accounts) into Multicall3 `aggregate3` txs, each sized to the block gas limit
+    #[structopt(long, conflicts_with_all = &["num-blocks", "presign"])]
+    aggregate: bool,
+
+    /// Open-loop approach: submit the (pre-signed) light, heavy or compute txs at this fixed rate
+    /// (in tx/s), regardless of the txs still pending, and report the submit & inclusion rates and
+    /// the backlog over time
+    #[structopt(long, conflicts_with_all = &["num-blocks", "presign", "aggregate"])]
+    tps: Option<f64>,
+
+    /// Open-loop approach, like `--tps`, but w/ the rate following a load profile: segments joined
+    /// by `+`, each one of `ramp:<from>-<to>:<secs>` (linear ramp), `step:<rate>,<rate>,...:<secs>`
+    /// (plateaus of `<secs>` each) or `spike:<base>,<peak>:<secs>:<spike secs>` (spike in the
+    /// middle of a baseline), w/ rates in tx/s. The throughput & error rate of each phase are
+    /// reported
+    #[structopt(long, conflicts_with_all = &["num-blocks", "presign", "aggregate", "tps"])]
+    load_profile: Option<LoadProfile>,
+
+    /// Pack up to this many `eth_sendRawTransaction`/`eth_getTransactionReceipt` calls into a single
+    /// JSON-RPC batch request over HTTP (with `--presign`)
+    #[structopt(long, requires = "presign")]
+    rpc_batch_size: Option<u16>,
+
     /// Initial funded account private key
     #[structopt(short = "k", long)]
     initial_funded_account_private_key: String,
@@ -42,16 +237,71 @@ struct Cli {
     #[structopt(short = "f", long)]
     funding_amount: u64,
 
-    /// Subspace EVM (Nova) RPC node URL
-    #[structopt(short = "r", long)]
-    rpc_url: String,
+    /// Subspace EVM (Nova) RPC node URL(s), comma-separated or repeated
+    #[structopt(short = "r", long, required = true, use_delimiter = true)]
+    rpc_url: Vec<String>,
+
+    /// Strategy to spread requests across the RPC node URLs: round-robin or least-in-flight
+    #[structopt(long, default_value = "round-robin")]
+    rpc_strategy: PoolStrategy,
+
+    /// Use a WebSocket transport (also picked for `ws://`/`wss://` RPC node URLs)
+    #[structopt(short = "w", long)]
+    ws: bool,
+
+    /// Max. share (in %) of the block gas limit used by each funding (`transferTsscToMany`) call
+    #[structopt(long, default_value = "50")]
+    funding_block_gas_share: u8,
+
+    /// Max. no. of retries of an RPC request failing with a transient error (rate-limited,
+    /// connection reset)
+    #[structopt(long, default_value = "5")]
+    max_retries: u32,
+
+    /// Base delay (in ms) of the jittered exponential backoff between retries
+    #[structopt(long, default_value = "250")]
+    retry_backoff_ms: u64,
+
+    /// Envelope of all the txs sent: legacy, eip2930 or eip1559
+    #[structopt(long, default_value = "eip1559")]
+    tx_envelope: TxEnvelope,
+
+    /// Gas price (in Gwei) of the legacy & eip2930 txs. Defaults to `GAS_PRICE` in the .env (if
+    /// set), otherwise to the node's gas price
+    #[structopt(long, parse(try_from_str = parse_gwei))]
+    gas_price: Option<U256>,
+
+    /// Max. fee per gas (in Gwei) of the eip1559 txs. Estimated by the node if not set
+    #[structopt(long, parse(try_from_str = parse_gwei))]
+    max_fee_per_gas: Option<U256>,
+
+    /// Max. priority fee per gas (in Gwei) of the eip1559 txs. Estimated by the node if not set
+    #[structopt(long, parse(try_from_str = parse_gwei))]
+    max_priority_fee_per_gas: Option<U256>,
+
+    /// Access list of the light, heavy & compute txs: none, rpc (via `eth_createAccessList`) or
+    /// static (from the storage slots known to be touched). Every other tx is sent w/o it, so as
+    /// to compare their gas used
+    #[structopt(long, default_value = "none")]
+    access_list: AccessListSource,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(clippy::upper_case_acronyms)]
 /// Transaction type
 enum TransactionType {
     LIGHT,
     HEAVY,
+    COMPUTE,
+    SETNUMBER,
+    CALLDATA,
+    TRANSFER,
+    ERC20,
+    DEPLOY,
+    MIXED,
+    LOGS,
+    INVALID,
+    ABI,
 }
 
 /// Implement `FromStr` trait for TransactionType
@@ -62,6 +312,16 @@ impl FromStr for TransactionType {
         match s.to_uppercase().as_str() {
             "LIGHT" => Ok(TransactionType::LIGHT),
             "HEAVY" => Ok(TransactionType::HEAVY),
+            "COMPUTE" => Ok(TransactionType::COMPUTE),
+            "SETNUMBER" => Ok(TransactionType::SETNUMBER),
+            "CALLDATA" => Ok(TransactionType::CALLDATA),
+            "TRANSFER" => Ok(TransactionType::TRANSFER),
+            "ERC20" => Ok(TransactionType::ERC20),
+            "DEPLOY" => Ok(TransactionType::DEPLOY),
+            "MIXED" => Ok(TransactionType::MIXED),
+            "LOGS" => Ok(TransactionType::LOGS),
+            "INVALID" => Ok(TransactionType::INVALID),
+            "ABI" => Ok(TransactionType::ABI),
             _ => Err(format!("\'{}\' is not a valid TransactionType", s)),
         }
     }
@@ -74,24 +334,154 @@ async fn main() -> Result<()> {
     // The new accounts are supposed to send transactions of type - "LIGHT"/"HEAVY"
     match opt.transaction_type.parse::<TransactionType>() {
         Ok(transaction_type) => {
+            // reject unsupported combinations before funding any account
+            if matches!(
+                transaction_type,
+                TransactionType::SETNUMBER
+                    | TransactionType::CALLDATA
+                    | TransactionType::TRANSFER
+                    | TransactionType::ERC20
+                    | TransactionType::DEPLOY
+                    | TransactionType::MIXED
+                    | TransactionType::LOGS
+                    | TransactionType::INVALID
+                    | TransactionType::ABI
+            ) && (opt.presign || opt.aggregate || opt.num_blocks.is_some())
+            {
+                bail!(
+                    "{:?} txs support none of `--presign`, `--aggregate` & `--num-blocks`",
+                    transaction_type
+                );
+            }
+            if let TransactionType::COMPUTE = transaction_type {
+                if opt.num_blocks.is_some() {
+                    bail!("COMPUTE txs don't support `--num-blocks`");
+                }
+            }
+            if matches!(transaction_type, TransactionType::COMPUTE | TransactionType::MIXED)
+                && opt.factorial_input > MAX_FACTORIAL_INPUT
+            {
+                bail!(
+                    "`--factorial-input` must be at most {}, as its factorial overflows beyond",
+                    MAX_FACTORIAL_INPUT
+                );
+            }
+            if matches!(transaction_type, TransactionType::COMPUTE | TransactionType::MIXED)
+                && opt.compute_rounds == 0
+            {
+                bail!("`--compute-rounds` must be positive");
+            }
+
+            if let TransactionType::LOGS = transaction_type {
+                if opt.log_fanout == 0 || opt.log_dust == 0 {
+                    bail!("`--log-fanout` & `--log-dust` must be positive, as `Fund` reverts w/o value");
+                }
+            }
+
+            match opt.tx_envelope {
+                TxEnvelope::Legacy | TxEnvelope::Eip2930 => {
+                    if opt.max_fee_per_gas.is_some() || opt.max_priority_fee_per_gas.is_some() {
+                        bail!("{:?} txs are priced by `--gas-price` only", opt.tx_envelope);
+                    }
+                }
+                TxEnvelope::Eip1559 => {
+                    if opt.gas_price.is_some() {
+                        bail!("Eip1559 txs are priced by `--max-fee-per-gas` & `--max-priority-fee-per-gas` only");
+                    }
+                    if let (Some(max_fee_per_gas), Some(max_priority_fee_per_gas)) =
+                        (opt.max_fee_per_gas, opt.max_priority_fee_per_gas)
+                    {
+                        if max_priority_fee_per_gas > max_fee_per_gas {
+                            bail!(
+                                "`--max-priority-fee-per-gas` must be at most `--max-fee-per-gas`"
+                            );
+                        }
+                    }
+                }
+            }
+
+            if let Some(tps) = opt.tps {
+                if !(tps.is_finite() && tps > 0.0) {
+                    bail!("`--tps` must be a positive rate");
+                }
+            }
+            if opt.tps.is_some() || opt.load_profile.is_some() {
+                if !matches!(
+                    transaction_type,
+                    TransactionType::LIGHT | TransactionType::HEAVY | TransactionType::COMPUTE
+                ) {
+                    bail!("{:?} txs don't support `--tps` or `--load-profile`", transaction_type);
+                }
+                if opt.access_list != AccessListSource::None {
+                    bail!("`--tps` & `--load-profile` don't support `--access-list`");
+                }
+            }
+            if opt.access_list != AccessListSource::None {
+                if !matches!(
+                    transaction_type,
+                    TransactionType::LIGHT | TransactionType::HEAVY | TransactionType::COMPUTE
+                ) {
+                    bail!("{:?} txs don't support `--access-list`", transaction_type);
+                }
+                if opt.presign || opt.aggregate || opt.num_blocks.is_some() {
+                    bail!("`--access-list` supports none of `--presign`, `--aggregate` & `--num-blocks`");
+                }
+                if opt.tx_envelope == TxEnvelope::Legacy {
+                    bail!("`--access-list` needs an eip2930 or eip1559 `--tx-envelope`");
+                }
+            }
+
+            // load the ABI-driven call
+            let abi_call = if let TransactionType::ABI = transaction_type {
+                match (&opt.abi_contract, &opt.abi_file, &opt.abi_function) {
+                    (Some(_), Some(abi_file), Some(abi_function)) => {
+                        Some(AbiCall::load(abi_file, abi_function, opt.abi_arg.clone())?)
+                    }
+                    _ => bail!("ABI txs need `--abi-contract`, `--abi-file` & `--abi-function`"),
+                }
+            } else {
+                None
+            };
+
             // get the .env
             dotenv::from_path("./dtp/.env").expect("Failed to get env variables");
 
             // init logger for debugging
             env_logger::init();
 
+            // set the envelope & fees of all the txs, incl. the funding ones
+            let gas_price = match (opt.tx_envelope, opt.gas_price, std::env::var("GAS_PRICE")) {
+                (TxEnvelope::Eip1559, _, _) => None,
+                (_, Some(gas_price), _) => Some(gas_price),
+                (_, None, Ok(gas_price)) if !gas_price.trim().is_empty() => {
+                    Some(parse_gwei(&gas_price).map_err(|e| eyre::eyre!("GAS_PRICE: {}", e))?)
+                }
+                _ => None,
+            };
+            let tx_fees = TxFees {
+                envelope: opt.tx_envelope,
+                gas_price,
+                max_fee_per_gas: opt.max_fee_per_gas,
+                max_priority_fee_per_gas: opt.max_priority_fee_per_gas,
+            };
+            println!("Sending {}", tx_fees);
+
             // get the env variables
             let (
                 counter_address,
                 load_address,
                 multicall_address,
                 fund_contract_addr,
+                erc20_address,
                 max_batch_size,
                 max_load_count_per_block,
             ) = get_env_vars().await?;
 
-            // connect to parsed Node RPC URL
-            let provider = Provider::<Http>::try_from(opt.rpc_url)
+            // connect to parsed Node RPC URL (over HTTP or WebSocket)
+            let retry_policy =
+                RetryPolicy { max_retries: opt.max_retries, backoff_ms: opt.retry_backoff_ms };
+            let provider = transport::connect(&opt.rpc_url, opt.ws, opt.rpc_strategy, retry_policy)
+                .await
                 .expect("Failed to connect! Please provide a valid RPC URL");
 
             // Create a shared reference across threads (in each `.await` call). looks synchronous, but many async calls are made here.
@@ -111,78 +501,377 @@ async fn main() -> Result<()> {
                 )
                 .await?;
 
-            // generate new accounts and transfer TSSC
+            // generate new accounts and transfer TSSC. W/ `--aggregate`, only the first account
+            // sends txs, so it alone gets the funding of all of them.
+            let (num_funded, funding_amount) = if opt.aggregate {
+                let funding_amount = opt
+                    .funding_amount
+                    .checked_mul(opt.num_accounts.into())
+                    .ok_or_else(|| eyre::eyre!("Funding amount of the sender overflows"))?;
+                (opt.num_accounts.min(1), funding_amount)
+            } else {
+                (opt.num_accounts, opt.funding_amount)
+            };
             let signers = gen_wallets_transfer_tssc(
                 client.clone(),
                 opt.num_accounts,
-                funder_wallet,
-                opt.funding_amount,
+                num_funded,
+                funder_wallet.clone(),
+                funding_amount,
                 fund_contract_addr,
                 chain_id,
+                &tx_fees,
+                opt.funding_block_gas_share,
             )
             .await?;
 
-            // handle light/heavy txs
-            if let TransactionType::LIGHT = transaction_type {
-                match opt.num_blocks {
-                    Some(num_blocks) => {
-                        // TODO: Bundle transactions and send in the {num_blocks} blocks based on different cases
-                        // There are 3 cases:
-                        // 1. num_accounts < num_blocks
-                        // 2. num_accounts = num_blocks
-                        // 3. num_accounts > num_blocks
-                    }
-                    None => {
-                        // TODO: The progress bar should be used like ... blinking or something to indicate that the program is still running.
-                        println!("Sending light transactions...");
-                        // Approach-2: All new wallet accounts are sender for each call individually
-                        // Say, all of them want to increment
-                        multicall_light_txs_2(
+            // fetch the starting nonce of each new account, so that it can send many txs w/o
+            // waiting for the previous receipts
+            let senders = Arc::new(
+                Senders::new(
+                    client.clone(),
+                    signers,
+                    chain_id,
+                    tx_fees,
+                    opt.txs_per_account,
+                    max_batch_size,
+                )
+                .await?,
+            );
+
+            // handle light/heavy/compute/setnumber/calldata/transfer/erc20/deploy/mixed/logs/invalid/abi txs
+            let mut transfer_totals = None;
+            let compute = Compute {
+                multicall_address,
+                load_address,
+                counter_address,
+                factorial_input: opt.factorial_input,
+                rounds: opt.compute_rounds,
+            };
+            // calldata is pre-encoded once (w/ `--presign`, `--aggregate`, `--tps`,
+            // `--load-profile` or `--access-list`), as all the txs (or calls) make the same call
+            let call = match transaction_type {
+                TransactionType::LIGHT => Some(PresignedCall::counter_increment(counter_address)),
+                TransactionType::HEAVY => {
+                    Some(PresignedCall::load_set_array(load_address, max_load_count_per_block))
+                }
+                TransactionType::COMPUTE => Some(PresignedCall::compute(&compute)),
+                _ => None,
+            };
+            if opt.presign || opt.aggregate {
+                let call = call.expect("rejected above");
+                if opt.aggregate {
+                    println!("Aggregating {:?} calls...", transaction_type);
+                    aggregate::aggregate_txs(
+                        client.clone(),
+                        multicall_address,
+                        senders.clone(),
+                        call,
+                        Duration::from_secs(opt.inclusion_timeout_secs),
+                    )
+                    .await?;
+
+                    println!("Aggregated transactions sent successfully.")
+                } else {
+                    // send & poll receipts in JSON-RPC batches, if requested
+                    let batch_client = opt
+                        .rpc_batch_size
+                        .map(|rpc_batch_size| {
+                            BatchClient::new(
+                                &opt.rpc_url,
+                                rpc_batch_size,
+                                retry_policy,
+                                client.as_ref().as_ref().stats(),
+                            )
+                        })
+                        .transpose()?;
+                    println!("Pre-signing & blasting {:?} transactions...", transaction_type);
+                    presign::presign_and_blast(
+                        client.clone(),
+                        batch_client,
+                        senders.clone(),
+                        call,
+                        max_batch_size,
+                        Duration::from_secs(opt.inclusion_timeout_secs),
+                    )
+                    .await?;
+
+                    println!("Pre-signed transactions sent successfully.")
+                }
+            } else if opt.tps.is_some() || opt.load_profile.is_some() {
+                let call = call.expect("rejected above");
+                let profile = match (opt.tps, opt.load_profile.clone()) {
+                    (_, Some(profile)) => profile,
+                    (Some(tps), None) => LoadProfile::constant(tps, senders.num_txs()),
+                    (None, None) => unreachable!("checked above"),
+                };
+                println!("Submitting {:?} transactions in open loop...", transaction_type);
+                open_loop::open_loop_txs(
+                    client.clone(),
+                    senders.clone(),
+                    call,
+                    profile,
+                    max_batch_size,
+                    Duration::from_secs(opt.inclusion_timeout_secs),
+                )
+                .await?;
+
+                println!("Open-loop transactions sent successfully.")
+            } else if opt.access_list != AccessListSource::None {
+                let call = call.expect("rejected above");
+                println!("Sending {:?} transactions w/ & w/o access lists...", transaction_type);
+                access_list::multicall_access_list_txs(
+                    client.clone(),
+                    senders.clone(),
+                    max_batch_size,
+                    call,
+                    opt.access_list,
+                    Duration::from_secs(opt.inclusion_timeout_secs),
+                )
+                .await?;
+
+                println!("Access list transactions sent successfully.")
+            } else {
+                match transaction_type {
+                    TransactionType::LIGHT => {
+                        match opt.num_blocks {
+                            Some(num_blocks) => {
+                                // Bundle transactions and send in the {num_blocks} blocks based on different cases
+                                // There are 3 cases:
+                                // 1. num_accounts < num_blocks
+                                // 2. num_accounts = num_blocks
+                                // 3. num_accounts > num_blocks
+                                println!("Sending light transactions in {} blocks...", num_blocks);
+                                multicall_light_txs_in_blocks(
+                                    client.clone(),
+                                    counter_address,
+                                    senders.clone(),
+                                    max_batch_size,
+                                    num_blocks,
+                                )
+                                .await?;
+
+                                println!("Light transactions sent successfully.")
+                            }
+                            None => {
+                                // TODO: The progress bar should be used like ... blinking or something to indicate that the program is still running.
+                                println!("Sending light transactions...");
+                                // Approach-2: All new wallet accounts are sender for each call individually
+                                // Say, all of them want to increment
+                                multicall_light_txs_2(
+                                    client.clone(),
+                                    counter_address,
+                                    senders.clone(),
+                                    max_batch_size,
+                                    Duration::from_secs(opt.inclusion_timeout_secs),
+                                )
+                                .await?;
+
+                                println!("Light transactions sent successfully.")
+                            }
+                        }
+                    }
+                    TransactionType::HEAVY => {
+                        match opt.num_blocks {
+                            Some(num_blocks) => {
+                                // Bundle transactions (packed by gas) and send in the {num_blocks} blocks
+                                println!("Sending heavy transactions in {} blocks...", num_blocks);
+                                multicall_heavy_txs_2(
+                                    client.clone(),
+                                    load_address,
+                                    senders.clone(),
+                                    max_batch_size,
+                                    max_load_count_per_block,
+                                    Some(num_blocks),
+                                )
+                                .await?;
+
+                                println!("Heavy transactions sent successfully.")
+                            }
+                            None => {
+                                // Bundle transactions (packed by gas) and send in the next available blocks
+                                // TODO: The progress bar should be used like ... blinking or something to indicate that the program is still running.
+                                println!("Sending heavy transactions...");
+                                // Approach-2: All new wallet accounts are sender for each call individually
+                                // Say, all of them want to increment
+                                multicall_heavy_txs_2(
+                                    client.clone(),
+                                    load_address,
+                                    senders.clone(),
+                                    max_batch_size,
+                                    max_load_count_per_block,
+                                    None,
+                                )
+                                .await?;
+
+                                println!("Heavy transactions sent successfully.")
+                            }
+                        }
+                    }
+                    TransactionType::COMPUTE => {
+                        println!("Sending compute transactions...");
+                        compute::multicall_compute_txs(
+                            client.clone(),
+                            &compute,
+                            senders.clone(),
+                            max_batch_size,
+                            Duration::from_secs(opt.inclusion_timeout_secs),
+                        )
+                        .await?;
+
+                        println!("Compute transactions sent successfully.")
+                    }
+                    TransactionType::SETNUMBER => {
+                        println!("Sending setNumber transactions...");
+                        set_number::multicall_set_number_txs(
                             client.clone(),
                             counter_address,
-                            signers,
-                            chain_id,
+                            senders.clone(),
                             max_batch_size,
+                            opt.set_number_values,
+                            Duration::from_secs(opt.inclusion_timeout_secs),
                         )
-                        .await
-                        .expect("Approach-2 failed when sending light txs.");
+                        .await?;
 
-                        println!("Light transactions sent successfully.")
+                        println!("SetNumber transactions sent successfully.")
                     }
-                }
-            } else if let TransactionType::HEAVY = transaction_type {
-                match opt.num_blocks {
-                    Some(num_blocks) => {
-                        // TODO: Bundle transactions and send in the {num_blocks} blocks based on different cases
-                        // There are 3 cases:
-                        // 1. num_accounts < num_blocks
-                        // 2. num_accounts = num_blocks
-                        // 3. num_accounts > num_blocks
-                    }
-                    None => {
-                        // TODO: Bundle transactions and send in the next available blocks
-                        // TODO: The progress bar should be used like ... blinking or something to indicate that the program is still running.
-                        println!("Sending heavy transactions...");
-                        // Approach-2: All new wallet accounts are sender for each call individually
-                        // Say, all of them want to increment
-                        multicall_heavy_txs_2(
+                    TransactionType::CALLDATA => {
+                        println!("Sending calldata transactions...");
+                        calldata::multicall_calldata_txs(
                             client.clone(),
+                            senders.clone(),
+                            max_batch_size,
+                            &opt.calldata_sizes,
+                            opt.calldata_to,
+                            Duration::from_secs(opt.inclusion_timeout_secs),
+                        )
+                        .await?;
+
+                        println!("Calldata transactions sent successfully.")
+                    }
+                    TransactionType::TRANSFER => {
+                        println!("Sending transfer transactions...");
+                        transfer_totals = Some(
+                            transfer::multicall_transfer_txs(
+                                client.clone(),
+                                senders.clone(),
+                                max_batch_size,
+                                opt.transfer_amount,
+                                opt.transfer_to,
+                                Duration::from_secs(opt.inclusion_timeout_secs),
+                            )
+                            .await?,
+                        );
+
+                        println!("Transfer transactions sent successfully.")
+                    }
+                    TransactionType::ERC20 => {
+                        println!("Sending ERC-20 transactions...");
+                        erc20::multicall_erc20_txs(
+                            client.clone(),
+                            erc20_address,
+                            funder_wallet,
+                            senders.clone(),
+                            max_batch_size,
+                            opt.erc20_amount,
+                            Duration::from_secs(opt.inclusion_timeout_secs),
+                        )
+                        .await?;
+
+                        println!("ERC-20 transactions sent successfully.")
+                    }
+                    TransactionType::DEPLOY => {
+                        println!("Sending deploy transactions...");
+                        deploy::multicall_deploy_txs(
+                            client.clone(),
+                            senders.clone(),
+                            max_batch_size,
+                            opt.deploy_contract,
+                            Duration::from_secs(opt.inclusion_timeout_secs),
+                        )
+                        .await?;
+
+                        println!("Deploy transactions sent successfully.")
+                    }
+                    TransactionType::MIXED => {
+                        println!("Sending mixed transactions...");
+                        mixed::multicall_mixed_txs(
+                            client.clone(),
+                            counter_address,
                             load_address,
-                            signers,
-                            chain_id,
+                            senders.clone(),
                             max_batch_size,
                             max_load_count_per_block,
+                            &compute,
+                            opt.transfer_amount,
+                            &opt.mix,
+                            Duration::from_secs(opt.inclusion_timeout_secs),
+                        )
+                        .await?;
+
+                        println!("Mixed transactions sent successfully.")
+                    }
+                    TransactionType::LOGS => {
+                        println!("Sending logs transactions...");
+                        logs::multicall_logs_txs(
+                            client.clone(),
+                            fund_contract_addr,
+                            senders.clone(),
+                            max_batch_size,
+                            opt.log_fanout,
+                            opt.log_dust,
+                            Duration::from_secs(opt.inclusion_timeout_secs),
+                        )
+                        .await?;
+
+                        println!("Logs transactions sent successfully.")
+                    }
+                    TransactionType::INVALID => {
+                        println!("Sending invalid transactions...");
+                        invalid::multicall_invalid_txs(
+                            client.clone(),
+                            fund_contract_addr,
+                            senders.clone(),
+                            max_batch_size,
+                            &opt.invalid_cases,
+                            Duration::from_secs(opt.inclusion_timeout_secs),
                         )
-                        .await
-                        .expect("Approach-2 failed when sending heavy txs.");
+                        .await?;
 
-                        println!("Heavy transactions sent successfully.")
+                        println!("Invalid transactions failed as expected.")
+                    }
+                    TransactionType::ABI => {
+                        let abi_call = abi_call.expect("loaded w/ the ABI tx type");
+                        println!("Sending \'{}\' transactions...", abi_call.name());
+                        abi_call::multicall_abi_txs(
+                            client.clone(),
+                            opt.abi_contract.expect("checked w/ the ABI call"),
+                            senders.clone(),
+                            max_batch_size,
+                            &abi_call,
+                            Duration::from_secs(opt.inclusion_timeout_secs),
+                        )
+                        .await?;
+
+                        println!("ABI transactions sent successfully.")
                     }
                 }
             }
 
             // Show the funder's final balance at the end
-            show_funder_final_balance(client, funder_address, funder_balance_wei_initial).await?;
+            show_funder_final_balance(
+                client.clone(),
+                funder_address,
+                funder_balance_wei_initial,
+                transfer_totals,
+            )
+            .await?;
+
+            // Show the retries & failures per class of RPC failure
+            println!("=====\n{}", client.as_ref().as_ref().stats());
+
+            // Show the health & latency per RPC endpoint
+            println!("{}", client.as_ref().as_ref().pool());
         }
         Err(e) => {
             bail!("{}", e);
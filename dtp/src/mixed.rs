@@ -0,0 +1,298 @@
+use crate::{
+    compute::Compute,
+    contracts::{
+        counter_increment_send, counter_set_number_send, load_set_array_estimate_gas,
+        load_set_array_send, multicall_aggregate3_estimate_gas, multicall_aggregate3_send,
+    },
+    transfer::transfer_send,
+    transport::Client,
+    utils::{run_tracked, Senders},
+    TransactionType,
+};
+use ethers::{core::rand::Rng, prelude::*};
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+
+/// Weights of the tx types drawn by the MIXED txs, e.g. `light=70,heavy=10,transfer=20`.
+#[derive(Debug, Clone)]
+pub(crate) struct Mix(Vec<(TransactionType, u32)>);
+
+/// Implement `FromStr` trait for Mix
+impl FromStr for Mix {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut weights = Vec::new();
+        for entry in s.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (name, weight) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("\'{}\' is not a valid `<type>=<weight>`", entry))?;
+            let transaction_type = name.trim().parse::<TransactionType>()?;
+            if !matches!(
+                transaction_type,
+                TransactionType::LIGHT
+                    | TransactionType::HEAVY
+                    | TransactionType::COMPUTE
+                    | TransactionType::SETNUMBER
+                    | TransactionType::TRANSFER
+            ) {
+                return Err(format!("{:?} txs can't be mixed", transaction_type));
+            }
+            let weight = weight
+                .trim()
+                .parse::<u32>()
+                .map_err(|e| format!("\'{}\' is not a valid weight: {}", weight, e))?;
+            weights.push((transaction_type, weight));
+        }
+        // the total is what `draw` draws below
+        let total = weights
+            .iter()
+            .try_fold(0_u32, |total, (_, weight)| total.checked_add(*weight))
+            .ok_or_else(|| format!("The weights of \'{}\' add up beyond {}", s, u32::MAX))?;
+        if total == 0 {
+            return Err(format!("\'{}\' has no positive weight", s));
+        }
+
+        Ok(Mix(weights))
+    }
+}
+
+impl Mix {
+    /// Draw a tx type w/ a probability proportional to its weight.
+    fn draw(&self, rng: &mut impl Rng) -> TransactionType {
+        let total: u32 = self.0.iter().map(|(_, weight)| weight).sum();
+        let mut point = rng.gen_range(0..total);
+        for (transaction_type, weight) in self.0.iter() {
+            if point < *weight {
+                return *transaction_type;
+            }
+            point -= weight;
+        }
+        unreachable!("point drawn below the total weight")
+    }
+}
+
+/// As MIXED transaction type, each tx of the new accounts is drawn from the `mix` of tx types, so
+/// that the blocks aren't made of a single kind of tx:
+/// - LIGHT: `Counter::increment()`
+/// - HEAVY: `Load::setArray(MAX_LOAD_COUNT_PER_BLOCK)`, w/ the gas estimated once
+/// - COMPUTE: `compute`'s `aggregate3` call, w/ the gas estimated once
+/// - SETNUMBER: `Counter::setNumber(<random>)`
+/// - TRANSFER: `transfer_amount` (in Wei) of TSSC to the next account
+///
+/// The txs are sent in batches of `MAX_BATCH_SIZE`, and the results are reported per tx type.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn multicall_mixed_txs(
+    client: Arc<Client>,
+    counter_address: Address,
+    load_address: Address,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    max_load_count_per_block: u16,
+    compute: &Compute,
+    transfer_amount: u64,
+    mix: &Mix,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let transfer_amount = U256::from(transfer_amount);
+
+    // draw the tx type of each tx
+    let mut rng = ethers::core::rand::thread_rng();
+    let types: Vec<TransactionType> = (0..senders.num_txs()).map(|_| mix.draw(&mut rng)).collect();
+
+    // the gas of `setArray` is estimated once for all the HEAVY txs
+    let heavy_gas = if types.contains(&TransactionType::HEAVY) {
+        load_set_array_estimate_gas(
+            client.clone(),
+            load_address,
+            senders.signer(0).to_owned(),
+            senders.chain_id,
+            &senders.fees,
+            max_load_count_per_block,
+            senders.fees.max_gas_price(client.as_ref()).await?,
+        )
+        .await?
+    } else {
+        U256::zero()
+    };
+    // and so is the gas of the COMPUTE txs
+    let compute_calls = compute.calls();
+    let compute_gas = if types.contains(&TransactionType::COMPUTE) {
+        multicall_aggregate3_estimate_gas(
+            client.clone(),
+            compute.multicall_address,
+            senders.signer(0).to_owned(),
+            senders.chain_id,
+            &senders.fees,
+            compute_calls.clone(),
+        )
+        .await?
+    } else {
+        U256::zero()
+    };
+
+    let txs: Vec<usize> = (0..senders.num_txs()).collect();
+    let (sent, report) = run_tracked(
+        client.clone(),
+        &senders,
+        &txs,
+        max_batch_size,
+        inclusion_timeout,
+        "mixed",
+        |tx, signer, nonce| {
+            let (client, chain_id, fees) = (client.clone(), senders.chain_id, &senders.fees);
+            let to = senders.signer(tx + 1).address();
+            let transaction_type = types[tx];
+            let compute_calls = &compute_calls;
+            async move {
+                match transaction_type {
+                    TransactionType::LIGHT => {
+                        counter_increment_send(
+                            client,
+                            counter_address,
+                            signer,
+                            chain_id,
+                            fees,
+                            nonce,
+                        )
+                        .await
+                    }
+                    TransactionType::HEAVY => {
+                        load_set_array_send(
+                            client,
+                            load_address,
+                            signer,
+                            chain_id,
+                            fees,
+                            max_load_count_per_block,
+                            heavy_gas,
+                            nonce,
+                        )
+                        .await
+                    }
+                    TransactionType::COMPUTE => {
+                        multicall_aggregate3_send(
+                            client,
+                            compute.multicall_address,
+                            signer,
+                            chain_id,
+                            fees,
+                            compute_calls.clone(),
+                            compute_gas,
+                            nonce,
+                        )
+                        .await
+                    }
+                    TransactionType::SETNUMBER => {
+                        let number = U256::from(H256::random().as_bytes());
+                        counter_set_number_send(
+                            client,
+                            counter_address,
+                            signer,
+                            chain_id,
+                            fees,
+                            number,
+                            nonce,
+                        )
+                        .await
+                    }
+                    TransactionType::TRANSFER => {
+                        transfer_send(client, signer, chain_id, fees, to, transfer_amount, nonce)
+                            .await
+                    }
+                    _ => unreachable!("rejected when parsing the mix"),
+                }
+            }
+        },
+    )
+    .await?;
+    let sent: HashMap<TxHash, TransactionType> =
+        sent.into_iter().map(|(tx, tx_hash)| (tx_hash, types[tx])).collect();
+
+    // the receipts of the included txs, per type
+    let mut num_sent: BTreeMap<TransactionType, usize> = BTreeMap::new();
+    for transaction_type in sent.values() {
+        *num_sent.entry(*transaction_type).or_default() += 1;
+    }
+    let stats = report.receipt_stats_by(|tx_hash| sent[tx_hash]);
+
+    println!(
+        "=====\n{:<10} {:>8} {:>9} {:>13} {:>9} {:>12}",
+        "type", "sent", "included", "not included", "reverted", "avg. gas"
+    );
+    for (transaction_type, num_sent) in num_sent.iter() {
+        let type_stats = stats.get(transaction_type).copied().unwrap_or_default();
+        println!(
+            "{:<10} {:>8} {:>9} {:>13} {:>9} {:>12}",
+            format!("{:?}", transaction_type),
+            num_sent,
+            type_stats.included,
+            num_sent - type_stats.included,
+            type_stats.reverted,
+            type_stats.avg_gas_used()
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::core::rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn mix_from_str() {
+        let mix: Mix = " light=70, heavy = 10,transfer=20,".parse().unwrap();
+        assert_eq!(
+            mix.0,
+            vec![
+                (TransactionType::LIGHT, 70),
+                (TransactionType::HEAVY, 10),
+                (TransactionType::TRANSFER, 20)
+            ]
+        );
+
+        for s in [
+            "light",
+            "light=",
+            "light=-1",
+            "light=1.5",
+            "unknown=1",
+            "mixed=1",
+            "light=0,heavy=0",
+            "",
+            "light=4294967295,heavy=1",
+        ] {
+            assert!(s.parse::<Mix>().is_err(), "{}", s);
+        }
+        assert!("light=4294967295,heavy=0".parse::<Mix>().is_ok());
+    }
+
+    #[test]
+    fn draw_follows_the_weights() {
+        let mix: Mix = "light=3,heavy=0,compute=1".parse().unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let mut drawn = BTreeMap::new();
+        for _ in 0..4000 {
+            *drawn.entry(mix.draw(&mut rng)).or_insert(0) += 1;
+        }
+        assert_eq!(drawn.get(&TransactionType::HEAVY), None);
+        let light = drawn[&TransactionType::LIGHT];
+        assert!((2800..3200).contains(&light), "{}", light);
+        assert_eq!(light + drawn[&TransactionType::COMPUTE], 4000);
+    }
+
+    #[test]
+    fn draw_w_a_single_positive_weight() {
+        let mix: Mix = "setnumber=0,transfer=4294967295".parse().unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+
+        assert!((0..100).all(|_| mix.draw(&mut rng) == TransactionType::TRANSFER));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::retry::RpcErrorClass;
+use ethers::prelude::*;
+use futures::{future::join_all, lock::Mutex as AsyncMutex};
+use std::{collections::HashMap, future::Future, sync::Mutex};
+
+/// Local nonce manager for the accounts sending txs.
+///
+/// Each account's starting nonce is fetched once from the node. Then, sequential nonces are handed
+/// out locally w/o waiting for the receipts of the previous txs, so that an account can have many
+/// txs in flight at once.
+///
+/// Sends via `send` are serialised per account: a nonce is handed out only once the previous send
+/// of the account returned. So, a failed send hands its nonce back (no gap is left behind it), and
+/// a resync after a nonce error can't hand out a nonce still being sent.
+#[derive(Debug, Default)]
+pub(crate) struct NonceManager {
+    nonces: Mutex<HashMap<Address, U256>>,
+    /// Lock of each account, held during its sends.
+    sends: HashMap<Address, AsyncMutex<()>>,
+}
+
+impl NonceManager {
+    /// Fetch the starting (pending) nonce of each of the `addresses` in chunks of
+    /// `MAX_BATCH_SIZE`.
+    pub(crate) async fn new<M: Middleware + 'static>(
+        client: &M,
+        addresses: &[Address],
+        max_batch_size: u16,
+    ) -> eyre::Result<Self> {
+        let mut nonces = HashMap::with_capacity(addresses.len());
+        for chunk in addresses.chunks(max_batch_size.into()) {
+            let batch = chunk.iter().map(|address| {
+                client.get_transaction_count(*address, Some(BlockNumber::Pending.into()))
+            });
+            for (address, nonce) in chunk.iter().zip(join_all(batch).await) {
+                nonces.insert(*address, nonce?);
+            }
+        }
+        let sends = addresses.iter().map(|address| (*address, AsyncMutex::new(()))).collect();
+
+        Ok(Self { nonces: Mutex::new(nonces), sends })
+    }
+
+    /// Hand out the next nonce of `address`.
+    pub(crate) fn next(&self, address: Address) -> eyre::Result<U256> {
+        let mut nonces = self.nonces.lock().unwrap();
+        let nonce = nonces
+            .get_mut(&address)
+            .ok_or_else(|| eyre::eyre!("No nonce is managed for {:?}", address))?;
+        let next = *nonce;
+        *nonce += U256::one();
+
+        Ok(next)
+    }
+
+    /// Hand `nonce` back, as the next nonce of `address`.
+    fn release(&self, address: Address, nonce: U256) {
+        log::debug!("Released nonce {} of {:?}", nonce, address);
+        self.nonces.lock().unwrap().insert(address, nonce);
+    }
+
+    /// Resync the nonce of `address` with the node's pending nonce.
+    pub(crate) async fn resync<M: Middleware + 'static>(
+        &self,
+        client: &M,
+        address: Address,
+    ) -> eyre::Result<()> {
+        let nonce =
+            client.get_transaction_count(address, Some(BlockNumber::Pending.into())).await?;
+        log::debug!("Resynced nonce of {:?} to {}", address, nonce);
+        self.nonces.lock().unwrap().insert(address, nonce);
+
+        Ok(())
+    }
+
+    /// Send a tx from `address` via `send` with the next nonce, once the previous send of
+    /// `address` returned. On a nonce error, the nonce is resynced and the tx is sent once more.
+    /// On any other error, the nonce is handed back for the next tx.
+    pub(crate) async fn send<M, F, Fut>(
+        &self,
+        client: &M,
+        address: Address,
+        send: F,
+    ) -> eyre::Result<TxHash>
+    where
+        M: Middleware + 'static,
+        F: Fn(U256) -> Fut,
+        Fut: Future<Output = eyre::Result<TxHash>>,
+    {
+        let _sending = self
+            .sends
+            .get(&address)
+            .ok_or_else(|| eyre::eyre!("No nonce is managed for {:?}", address))?
+            .lock()
+            .await;
+
+        let nonce = self.next(address)?;
+        let (nonce, res) = match send(nonce).await {
+            Err(err)
+                if RpcErrorClass::classify_msg(&err.to_string()) == RpcErrorClass::NonceTooLow =>
+            {
+                self.resync(client, address).await?;
+                let nonce = self.next(address)?;
+                (nonce, send(nonce).await)
+            }
+            res => (nonce, res),
+        };
+        if res.is_err() {
+            self.release(address, nonce);
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Manager of `address`, starting at `nonce` as per a mocked node.
+    async fn manager(address: Address, nonce: u64) -> (NonceManager, Provider<MockProvider>) {
+        let (client, mock) = Provider::mocked();
+        mock.push(U256::from(nonce)).unwrap();
+        let nonces = NonceManager::new(&client, &[address], 10).await.unwrap();
+
+        (nonces, client)
+    }
+
+    #[tokio::test]
+    async fn next_hands_out_sequential_nonces() {
+        let address = Address::random();
+        let (nonces, _) = manager(address, 7).await;
+
+        assert_eq!(nonces.next(address).unwrap(), 7.into());
+        assert_eq!(nonces.next(address).unwrap(), 8.into());
+        assert!(nonces.next(Address::random()).is_err());
+    }
+
+    #[tokio::test]
+    async fn resync_takes_the_node_pending_nonce() {
+        let address = Address::random();
+        let (nonces, client) = manager(address, 7).await;
+        nonces.next(address).unwrap();
+        nonces.next(address).unwrap();
+
+        client.as_ref().push(U256::from(3)).unwrap();
+        nonces.resync(&client, address).await.unwrap();
+        assert_eq!(nonces.next(address).unwrap(), 3.into());
+    }
+
+    #[tokio::test]
+    async fn failed_send_hands_its_nonce_back() {
+        let address = Address::random();
+        let (nonces, client) = manager(address, 5).await;
+
+        let res = nonces
+            .send(&client, address, |_| async { Err(eyre::eyre!("transaction underpriced")) })
+            .await;
+        assert!(res.is_err());
+        assert_eq!(nonces.next(address).unwrap(), 5.into());
+    }
+
+    #[tokio::test]
+    async fn nonce_too_low_resyncs_and_sends_once_more() {
+        let address = Address::random();
+        let (nonces, client) = manager(address, 5).await;
+        client.as_ref().push(U256::from(9)).unwrap();
+
+        let used = Mutex::new(Vec::new());
+        let res = nonces
+            .send(&client, address, |nonce| {
+                used.lock().unwrap().push(nonce);
+                async move {
+                    match nonce.as_u64() {
+                        5 => Err(eyre::eyre!("nonce too low: next nonce 9, tx nonce 5")),
+                        _ => Ok(TxHash::random()),
+                    }
+                }
+            })
+            .await;
+        assert!(res.is_ok());
+        assert_eq!(*used.lock().unwrap(), vec![U256::from(5), U256::from(9)]);
+        assert_eq!(nonces.next(address).unwrap(), 10.into());
+    }
+
+    #[tokio::test]
+    async fn concurrent_sends_leave_no_gap() {
+        let address = Address::random();
+        let (nonces, client) = manager(address, 0).await;
+
+        // every other send fails, while the sends of the account are in flight at once
+        let attempts = AtomicUsize::new(0);
+        let sent = Mutex::new(Vec::new());
+        let sends = (0..6).map(|_| {
+            nonces.send(&client, address, |nonce| {
+                let failed = attempts.fetch_add(1, Ordering::SeqCst) % 2 == 1;
+                if !failed {
+                    sent.lock().unwrap().push(nonce);
+                }
+                async move {
+                    tokio::task::yield_now().await;
+                    match failed {
+                        true => Err(eyre::eyre!("insufficient funds")),
+                        false => Ok(TxHash::random()),
+                    }
+                }
+            })
+        });
+        join_all(sends).await;
+
+        assert_eq!(*sent.lock().unwrap(), vec![U256::from(0), U256::from(1), U256::from(2)]);
+        assert_eq!(nonces.next(address).unwrap(), 3.into());
+    }
+}
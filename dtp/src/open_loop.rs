@@ -0,0 +1,568 @@
+use crate::{
+    fees::wei_to_gwei_string,
+    presign::{presign_txs, report_inclusion_rate, PresignedCall},
+    tracker::InclusionTracker,
+    transport::Client,
+    utils::Senders,
+};
+use ethers::prelude::*;
+use futures::future::join_all;
+use std::{
+    collections::BTreeMap,
+    fmt,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// Interval (in secs) at which the progress of an open-loop run is reported.
+const PROGRESS_INTERVAL_SECS: u64 = 1;
+
+/// Tolerance (in secs or txs) of the float rounding, under which a tx due right at the end of a
+/// phase is left to the next one.
+const SCHEDULE_TOLERANCE: f64 = 1e-9;
+
+/// Factor of the current base fee covered by the max. fee per gas of the open-loop txs (unless
+/// set), as they're all signed before the run, while the base fee may rise during it.
+const BASE_FEE_HEADROOM: u64 = 4;
+
+/// Phase of a load profile, whose rate goes linearly from `from_tps` to `to_tps` (in tx/s).
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Phase {
+    pub(crate) name: String,
+    pub(crate) from_tps: f64,
+    pub(crate) to_tps: f64,
+    pub(crate) duration: Duration,
+}
+
+impl Phase {
+    /// Expected no. of txs submitted over the whole phase.
+    fn expected_txs(&self) -> f64 {
+        (self.from_tps + self.to_tps) / 2.0 * self.duration.as_secs_f64()
+    }
+
+    /// Time (in secs) into the phase by which `txs` txs are expected to be submitted, i.e. the
+    /// inverse of the rate integrated over time, `from * t + (to - from) / duration * t^2 / 2`.
+    fn secs_to_submit(&self, txs: f64) -> f64 {
+        let slope = (self.to_tps - self.from_tps) / self.duration.as_secs_f64();
+        // the root of the quadratic, in a form that holds w/o slope
+        2.0 * txs / (self.from_tps + (self.from_tps.powi(2) + 2.0 * slope * txs).sqrt())
+    }
+}
+
+/// Submit rate as a function of time, as a sequence of phases.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LoadProfile {
+    pub(crate) phases: Vec<Phase>,
+}
+
+/// Implement `FromStr` trait for LoadProfile, from segments joined by `+`, each one of:
+/// - `ramp:<from>-<to>:<secs>`: linear ramp from `<from>` to `<to>` tx/s over `<secs>`
+/// - `step:<rate>,<rate>,...:<secs>`: plateaus of `<secs>` each, at the given rates
+/// - `spike:<base>,<peak>:<secs>:<spike secs>`: `<base>` tx/s over `<secs>`, w/ a spike at
+///   `<peak>` tx/s for `<spike secs>` in the middle
+impl FromStr for LoadProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rate = |rate: &str| match rate.trim().parse::<f64>() {
+            Ok(rate) if rate.is_finite() && rate > 0.0 => Ok(rate),
+            _ => Err(format!("\'{}\' is not a positive rate (in tx/s)", rate)),
+        };
+        let secs = |secs: &str| match secs.trim().parse::<f64>() {
+            Ok(secs) if secs.is_finite() && secs > 0.0 => Ok(Duration::from_secs_f64(secs)),
+            _ => Err(format!("\'{}\' is not a positive duration (in secs)", secs)),
+        };
+        let phase =
+            |name: String, from_tps, to_tps, duration| Phase { name, from_tps, to_tps, duration };
+
+        let mut phases = Vec::new();
+        for segment in s.split('+') {
+            let parts: Vec<&str> = segment.trim().split(':').collect();
+            match parts.as_slice() {
+                ["ramp", rates, duration] => {
+                    let (from, to) = rates
+                        .split_once('-')
+                        .ok_or_else(|| format!("\'{}\' is not a ramp <from>-<to>", rates))?;
+                    let (from, to) = (rate(from)?, rate(to)?);
+                    phases.push(phase(format!("ramp {}-{}", from, to), from, to, secs(duration)?));
+                }
+                ["step", rates, duration] => {
+                    let duration = secs(duration)?;
+                    for step in rates.split(',') {
+                        let step = rate(step)?;
+                        phases.push(phase(format!("step {}", step), step, step, duration));
+                    }
+                }
+                ["spike", rates, duration, spike_duration] => {
+                    let (base, peak) = rates
+                        .split_once(',')
+                        .ok_or_else(|| format!("\'{}\' is not a spike <base>,<peak>", rates))?;
+                    let (base, peak) = (rate(base)?, rate(peak)?);
+                    let (duration, spike_duration) = (secs(duration)?, secs(spike_duration)?);
+                    if spike_duration >= duration {
+                        return Err(format!(
+                            "The spike of \'{}\' must be shorter than its baseline",
+                            segment
+                        ));
+                    }
+                    let baseline = (duration - spike_duration) / 2;
+                    phases.push(phase(format!("base {}", base), base, base, baseline));
+                    phases.push(phase(format!("spike {}", peak), peak, peak, spike_duration));
+                    phases.push(phase(format!("base {}", base), base, base, baseline));
+                }
+                _ => return Err(format!("\'{}\' is not a valid LoadProfile segment", segment)),
+            }
+        }
+
+        Ok(LoadProfile { phases })
+    }
+}
+
+impl fmt::Display for LoadProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phases: Vec<String> = self
+            .phases
+            .iter()
+            .map(|phase| format!("{} tx/s for {:.1?}", phase.name, phase.duration))
+            .collect();
+        write!(f, "{}", phases.join(", then "))
+    }
+}
+
+impl LoadProfile {
+    /// Constant rate of `tps` tx/s, for as long as it takes to submit `num_txs` txs.
+    pub(crate) fn constant(tps: f64, num_txs: usize) -> Self {
+        LoadProfile {
+            phases: vec![Phase {
+                name: format!("constant {}", tps),
+                from_tps: tps,
+                to_tps: tps,
+                duration: Duration::from_secs_f64(num_txs as f64 / tps),
+            }],
+        }
+    }
+
+    /// Expected no. of txs submitted by following the whole profile, as scheduled by `schedule`.
+    fn expected_txs(&self) -> usize {
+        let txs: f64 = self.phases.iter().map(Phase::expected_txs).sum();
+        (txs - SCHEDULE_TOLERANCE).ceil().max(0.0) as usize
+    }
+
+    /// Schedule of the txs, as the phase & the offset (since the start) of each one.
+    ///
+    /// The `tx`-th tx is due as soon as `tx` txs are expected to be submitted before it, as per the
+    /// rate integrated over time. So, the offsets don't drift from adding up the spacings, and
+    /// e.g. `n` tx/s over 1 sec make exactly `n` txs.
+    fn schedule(&self) -> impl Iterator<Item = (usize, Duration)> + '_ {
+        // the phase, along w/ its start & the txs expected before it
+        let (mut phase, mut phase_start, mut phase_txs) = (0, 0.0, 0.0);
+        let mut tx = 0;
+        std::iter::from_fn(move || loop {
+            let current = self.phases.get(phase)?;
+            let elapsed = current.secs_to_submit(tx as f64 - phase_txs);
+            if elapsed < current.duration.as_secs_f64() - SCHEDULE_TOLERANCE {
+                tx += 1;
+                return Some((phase, Duration::from_secs_f64(phase_start + elapsed)));
+            }
+            phase_start += current.duration.as_secs_f64();
+            phase_txs += current.expected_txs();
+            phase += 1;
+        })
+    }
+}
+
+/// Submissions of an open-loop run, updated by the send tasks.
+#[derive(Debug, Default)]
+struct Submissions {
+    submitted: AtomicUsize,
+    accepted: AtomicUsize,
+    rejected: AtomicUsize,
+}
+
+/// Progress of an open-loop run, reported every `PROGRESS_INTERVAL_SECS`.
+struct Progress {
+    started: Instant,
+    /// Submitted & included txs at the last report.
+    last: (usize, usize),
+}
+
+impl Progress {
+    /// Print the submitted, included & pending (accepted, but not yet included) txs so far in
+    /// `phase`, along with the rates since the last report.
+    fn print(&mut self, phase: &str, submissions: &Submissions, tracker: &InclusionTracker) {
+        let submitted = submissions.submitted.load(Ordering::Relaxed);
+        let accepted = submissions.accepted.load(Ordering::Relaxed);
+        let rejected = submissions.rejected.load(Ordering::Relaxed);
+        let included = tracker.num_included();
+        println!(
+            "{:>6.1}s {:<16} {:>10} {:>10.1} {:>9} {:>10} {:>10.1} {:>8}",
+            self.started.elapsed().as_secs_f64(),
+            phase,
+            submitted,
+            (submitted - self.last.0) as f64 / PROGRESS_INTERVAL_SECS as f64,
+            rejected,
+            included,
+            (included - self.last.1) as f64 / PROGRESS_INTERVAL_SECS as f64,
+            accepted.saturating_sub(included)
+        );
+        self.last = (submitted, included);
+    }
+}
+
+/// A tx submitted during an open-loop run, w/ its hash if accepted.
+struct SentTx {
+    phase: usize,
+    from: Address,
+    nonce: U256,
+    tx_hash: Option<TxHash>,
+}
+
+/// Outcome of the txs submitted during a phase.
+#[derive(Debug, Default)]
+struct PhaseStats {
+    submitted: usize,
+    rejected: usize,
+    /// Accepted txs queued behind a nonce gap, i.e. a rejected tx of the same sender.
+    stuck: usize,
+    included: usize,
+    /// Actual time spent submitting the phase's txs.
+    elapsed: Duration,
+}
+
+/// Open-loop approach: unlike the other approaches, which await each batch of `MAX_BATCH_SIZE`
+/// txs before sending the next one (so that the offered load depends on the node's latency), the
+/// txs making `call` are submitted at the rate of the load `profile`, regardless of the txs still
+/// pending. The txs are pre-signed first (see `presign_txs`), so that signing doesn't cap the rate,
+/// and the run ends early if the profile needs more of them.
+///
+/// Each tx is sent via `eth_sendRawTransaction` in its own task, and tracked by an
+/// `InclusionTracker` once accepted. Every `PROGRESS_INTERVAL_SECS`, the submit rate, the
+/// inclusion rate and the backlog of pending txs are reported, until all of them are settled.
+/// Then, the throughput & error rate of each phase are reported.
+///
+/// A rejected tx leaves a nonce gap, so the later txs of its sender can't be included even if
+/// accepted. These stuck txs aren't awaited, and are reported apart, per phase & per sender, as
+/// they tell nothing about the node's throughput.
+pub(crate) async fn open_loop_txs(
+    client: Arc<Client>,
+    senders: Arc<Senders>,
+    call: PresignedCall,
+    profile: LoadProfile,
+    max_batch_size: u16,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let fees = senders.fees.resolve_w_headroom(client.as_ref(), BASE_FEE_HEADROOM).await?;
+    let signed_txs = presign_txs(client.clone(), senders.as_ref(), &call, &fees).await?;
+    let num_txs = signed_txs.len();
+    println!("Submitting \'{}\' txs at {}...", call.name, profile);
+    let expected_txs = profile.expected_txs();
+    if expected_txs > num_txs {
+        println!(
+            "The profile needs ~{} txs, but only {} are pre-signed: it will be cut short (raise `--num-accounts` or `--txs-per-account`)",
+            expected_txs, num_txs
+        );
+    }
+
+    let tracker = Arc::new(InclusionTracker::start(client.clone(), inclusion_timeout).await?);
+    let submissions = Arc::new(Submissions::default());
+
+    let mut reporter = tokio::time::interval(Duration::from_secs(PROGRESS_INTERVAL_SECS));
+    reporter.tick().await;
+    println!(
+        "=====\n{:>7} {:<16} {:>10} {:>10} {:>9} {:>10} {:>10} {:>8}",
+        "time", "phase", "submitted", "submit/s", "rejected", "included", "include/s", "backlog"
+    );
+    let started = Instant::now();
+    let mut progress = Progress { started, last: (0, 0) };
+    let mut handles = Vec::with_capacity(num_txs);
+    let mut phase_started = vec![None; profile.phases.len()];
+    let mut current = 0;
+    // a tx due in the past (e.g. w/ a rate beyond the timer's resolution) is sent right away, so
+    // that the offered rate holds on average
+    for ((phase, offset), signed_tx) in profile.schedule().zip(signed_txs) {
+        let send_at = tokio::time::Instant::from_std(started + offset);
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep_until(send_at) => break,
+                _ = reporter.tick() => {
+                    progress.print(&profile.phases[current].name, &submissions, &tracker)
+                }
+            }
+        }
+        current = phase;
+        phase_started[phase].get_or_insert_with(|| started.elapsed());
+
+        submissions.submitted.fetch_add(1, Ordering::Relaxed);
+        let client = client.clone();
+        let tracker = tracker.clone();
+        let submissions = submissions.clone();
+        handles.push(tokio::spawn(async move {
+            let tx_hash = match client.send_raw_transaction(signed_tx.raw).await {
+                Ok(pending_tx) => {
+                    let tx_hash = pending_tx.tx_hash();
+                    tracker.track(tx_hash);
+                    submissions.accepted.fetch_add(1, Ordering::Relaxed);
+                    Some(tx_hash)
+                }
+                Err(e) => {
+                    log::debug!("tx {:?} rejected: {}", signed_tx.hash, e);
+                    submissions.rejected.fetch_add(1, Ordering::Relaxed);
+                    None
+                }
+            };
+            SentTx { phase, from: signed_tx.from, nonce: signed_tx.nonce, tx_hash }
+        }));
+    }
+    let submit_time = started.elapsed();
+
+    // keep reporting while the sends complete & the backlog drains
+    let mut sends = join_all(handles);
+    let drain_started = Instant::now();
+    let sent = loop {
+        tokio::select! {
+            res = &mut sends => break res.into_iter().collect::<Result<Vec<_>, _>>()?,
+            _ = reporter.tick() => progress.print("drain", &submissions, &tracker),
+        }
+    };
+
+    // the first rejected nonce of each sender, behind which its later txs are stuck
+    let mut gaps: BTreeMap<Address, U256> = BTreeMap::new();
+    for sent_tx in sent.iter().filter(|sent_tx| sent_tx.tx_hash.is_none()) {
+        let gap = gaps.entry(sent_tx.from).or_insert(sent_tx.nonce);
+        *gap = sent_tx.nonce.min(*gap);
+    }
+    let is_stuck = |sent_tx: &SentTx| {
+        sent_tx.tx_hash.is_some() && gaps.get(&sent_tx.from).is_some_and(|gap| sent_tx.nonce > *gap)
+    };
+    // the stuck txs aren't awaited, and are reported apart from those not included
+    let mut num_stuck = 0;
+    for sent_tx in sent.iter().filter(|sent_tx| is_stuck(sent_tx)) {
+        if let Some(tx_hash) = &sent_tx.tx_hash {
+            tracker.untrack(tx_hash);
+            num_stuck += 1;
+        }
+    }
+
+    while tracker.num_included() < submissions.accepted.load(Ordering::Relaxed) - num_stuck
+        && drain_started.elapsed() < inclusion_timeout
+    {
+        reporter.tick().await;
+        progress.print("drain", &submissions, &tracker);
+    }
+
+    let tracker = Arc::try_unwrap(tracker)
+        .map_err(|_| eyre::eyre!("Inclusion tracker still shared by a send task"))?;
+    let report = tracker.finish().await?;
+    report.print(&call.name);
+
+    // each phase lasts from its first submission until the next phase's (or the last submission)
+    let mut stats: Vec<PhaseStats> = profile.phases.iter().map(|_| Default::default()).collect();
+    // per sender w/ a nonce gap: its rejected & stuck txs
+    let mut gapped: BTreeMap<Address, (usize, usize)> = BTreeMap::new();
+    for sent_tx in sent.iter() {
+        let phase_stats = &mut stats[sent_tx.phase];
+        phase_stats.submitted += 1;
+        match sent_tx.tx_hash {
+            Some(tx_hash) if report.included.contains_key(&tx_hash) => phase_stats.included += 1,
+            Some(_) if is_stuck(sent_tx) => {
+                phase_stats.stuck += 1;
+                gapped.entry(sent_tx.from).or_default().1 += 1;
+            }
+            Some(_) => {}
+            None => {
+                phase_stats.rejected += 1;
+                gapped.entry(sent_tx.from).or_default().0 += 1;
+            }
+        }
+    }
+    let mut phase_ended = submit_time;
+    for (phase, phase_stats) in stats.iter_mut().enumerate().rev() {
+        if let Some(phase_started) = phase_started[phase] {
+            phase_stats.elapsed = phase_ended.saturating_sub(phase_started);
+            phase_ended = phase_started;
+        }
+    }
+
+    println!(
+        "=====\n{:<16} {:>10} {:>10} {:>9} {:>10} {:>10} {:>10} {:>10} {:>10}",
+        "phase",
+        "target/s",
+        "submitted",
+        "submit/s",
+        "rejected",
+        "stuck",
+        "included",
+        "include/s",
+        "errors (%)"
+    );
+    for (phase, phase_stats) in profile.phases.iter().zip(stats.iter()) {
+        let secs = phase_stats.elapsed.as_secs_f64().max(f64::EPSILON);
+        let failed = phase_stats.submitted - phase_stats.included;
+        println!(
+            "{:<16} {:>10} {:>10} {:>9.1} {:>10} {:>10} {:>10} {:>10.1} {:>10.2}",
+            phase.name,
+            if phase.from_tps == phase.to_tps {
+                format!("{}", phase.from_tps)
+            } else {
+                format!("{}-{}", phase.from_tps, phase.to_tps)
+            },
+            phase_stats.submitted,
+            phase_stats.submitted as f64 / secs,
+            phase_stats.rejected,
+            phase_stats.stuck,
+            phase_stats.included,
+            phase_stats.included as f64 / secs,
+            failed as f64 * 100.0 / phase_stats.submitted.max(1) as f64
+        );
+    }
+    for (from, (rejected, stuck)) in gapped.iter() {
+        println!(
+            "Sender {:?}: {} txs rejected (first at nonce {}), leaving {} accepted txs stuck",
+            from, rejected, gaps[from], stuck
+        );
+    }
+    println!(
+        "Submit: {} txs ({} accepted, {} rejected) in {:.2?} ({:.2} tx/s). Errors: rejected, stuck behind a rejected tx, or not included within {:?}",
+        sent.len(),
+        submissions.accepted.load(Ordering::Relaxed),
+        submissions.rejected.load(Ordering::Relaxed),
+        submit_time,
+        sent.len() as f64 / submit_time.as_secs_f64(),
+        inclusion_timeout
+    );
+    let block_numbers: Vec<U64> =
+        report.included.values().map(|inclusion| inclusion.block_number).collect();
+    report_inclusion_rate(client.clone(), block_numbers.len(), &block_numbers).await?;
+
+    // the txs are signed before the run, so the base fee may have outgrown their max. fee
+    if let Some(max_fee_per_gas) = fees.max_fee_per_gas {
+        let base_fee =
+            client.get_block(BlockNumber::Latest).await?.and_then(|block| block.base_fee_per_gas);
+        if let Some(base_fee) = base_fee.filter(|base_fee| *base_fee > max_fee_per_gas) {
+            println!(
+                "The base fee ({} Gwei) rose above the max. fee per gas of the txs ({} Gwei), so the pending ones can't be included: raise `--max-fee-per-gas`",
+                wei_to_gwei_string(base_fee),
+                wei_to_gwei_string(max_fee_per_gas)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase(name: &str, from_tps: f64, to_tps: f64, secs: f64) -> Phase {
+        Phase { name: name.to_string(), from_tps, to_tps, duration: Duration::from_secs_f64(secs) }
+    }
+
+    #[test]
+    fn load_profile_from_str() {
+        let profile: LoadProfile =
+            "ramp:10-50:30 + step:5,20:10+spike:10,100:60:20".parse().unwrap();
+        assert_eq!(
+            profile.phases,
+            vec![
+                phase("ramp 10-50", 10.0, 50.0, 30.0),
+                phase("step 5", 5.0, 5.0, 10.0),
+                phase("step 20", 20.0, 20.0, 10.0),
+                // the spike sits in the middle of its baseline
+                phase("base 10", 10.0, 10.0, 20.0),
+                phase("spike 100", 100.0, 100.0, 20.0),
+                phase("base 10", 10.0, 10.0, 20.0),
+            ]
+        );
+        let profile: LoadProfile = "ramp:0.5-2.5:1.5".parse().unwrap();
+        assert_eq!(profile.phases, vec![phase("ramp 0.5-2.5", 0.5, 2.5, 1.5)]);
+
+        for s in [
+            "",
+            "ramp:10:30",
+            "ramp:10-50",
+            "ramp:0-50:30",
+            "ramp:10-50:0",
+            "ramp:10-inf:30",
+            "ramp:NaN-50:30",
+            "step:5,-20:10",
+            "step:5,,20:10",
+            "step:5:-1",
+            // the spike must be shorter than its baseline
+            "spike:10,100:60:60",
+            "spike:10,100:60:61",
+            "spike:10:60:20",
+            "constant:10:60",
+            "ramp:10-50:30+",
+        ] {
+            assert!(s.parse::<LoadProfile>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn schedule_spaces_a_constant_rate_evenly() {
+        let profile = LoadProfile::constant(10.0, 20);
+        let schedule: Vec<(usize, Duration)> = profile.schedule().collect();
+
+        assert_eq!(schedule.len(), 20);
+        assert_eq!(profile.expected_txs(), 20);
+        for (tx, (phase, offset)) in schedule.into_iter().enumerate() {
+            assert_eq!(phase, 0);
+            assert!((offset.as_secs_f64() - tx as f64 / 10.0).abs() < 1e-6, "{:?}", offset);
+        }
+    }
+
+    #[test]
+    fn schedule_starts_each_step_at_its_boundary() {
+        let profile: LoadProfile = "step:3,7:2".parse().unwrap();
+        let schedule: Vec<(usize, Duration)> = profile.schedule().collect();
+
+        assert_eq!(schedule.iter().filter(|(phase, _)| *phase == 0).count(), 6);
+        assert_eq!(schedule.iter().filter(|(phase, _)| *phase == 1).count(), 14);
+        assert_eq!(schedule[6], (1, Duration::from_secs(2)));
+        assert!(schedule.last().unwrap().1 < Duration::from_secs(4));
+    }
+
+    #[test]
+    fn schedule_follows_a_ramp_between_its_endpoints() {
+        for s in ["ramp:1-10:10", "ramp:10-1:10"] {
+            let profile: LoadProfile = s.parse().unwrap();
+            let offsets: Vec<f64> =
+                profile.schedule().map(|(_, offset)| offset.as_secs_f64()).collect();
+
+            assert_eq!(offsets.len(), 55, "{}", s);
+            assert_eq!(offsets[0], 0.0, "{}", s);
+            assert!(*offsets.last().unwrap() < 10.0, "{}", s);
+            assert!(offsets.windows(2).all(|pair| pair[0] < pair[1]), "{}", s);
+        }
+
+        // the spacing follows the rate: the 2nd tx is due once `t + 0.45 * t^2 = 1`, and the last
+        // ones are spaced by close to 1/10 sec
+        let profile: LoadProfile = "ramp:1-10:10".parse().unwrap();
+        let offsets: Vec<f64> =
+            profile.schedule().map(|(_, offset)| offset.as_secs_f64()).collect();
+        assert!((offsets[1] - 0.7481).abs() < 1e-3, "{}", offsets[1]);
+        assert!(offsets[54] - offsets[53] < 0.11, "{:?}", &offsets[53..]);
+    }
+
+    #[test]
+    fn expected_txs_is_the_no_of_txs_scheduled() {
+        for s in [
+            "ramp:1-10:10",
+            "ramp:0.5-1.5:3",
+            "step:0.3:1",
+            "step:1,2,3:1.5",
+            "spike:2,20:10:1",
+            "spike:3,7:2.5:0.5+ramp:4-1:3",
+        ] {
+            let profile: LoadProfile = s.parse().unwrap();
+            assert_eq!(profile.expected_txs(), profile.schedule().count(), "{}", s);
+        }
+        // 0.3 tx/s over 1 sec still sends the tx due at its start
+        assert_eq!("step:0.3:1".parse::<LoadProfile>().unwrap().expected_txs(), 1);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{retry::RpcErrorClass, transport::Transport};
+use async_trait::async_trait;
+use ethers::prelude::*;
+use futures::channel::mpsc;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::value::RawValue;
+use std::{
+    fmt::{self, Debug},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// Duration (in secs) for which an endpoint failing with a transient error (rate-limited,
+/// connection reset) is ejected from the pool.
+const EJECT_DURATION_SECS: u64 = 30;
+
+/// Strategy to pick an endpoint of the pool for each request.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PoolStrategy {
+    /// Endpoints take turns.
+    RoundRobin,
+    /// The endpoint with the fewest requests in flight.
+    LeastInFlight,
+}
+
+/// Implement `FromStr` trait for PoolStrategy
+impl FromStr for PoolStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('_', "-").as_str() {
+            "round-robin" => Ok(PoolStrategy::RoundRobin),
+            "least-in-flight" => Ok(PoolStrategy::LeastInFlight),
+            _ => Err(format!("\'{}\' is not a valid PoolStrategy", s)),
+        }
+    }
+}
+
+/// Health & latency of an endpoint.
+#[derive(Debug, Default)]
+struct EndpointStats {
+    requests: u64,
+    failures: u64,
+    total_latency: Duration,
+    ejections: u64,
+    ejected_until: Option<Instant>,
+}
+
+/// An RPC node endpoint of the pool.
+#[derive(Debug)]
+struct Endpoint {
+    url: String,
+    transport: Transport,
+    in_flight: AtomicUsize,
+    stats: Mutex<EndpointStats>,
+}
+
+impl Endpoint {
+    /// Instant until which the endpoint is ejected, if it is right now.
+    fn ejected_until(&self, now: Instant) -> Option<Instant> {
+        self.stats.lock().unwrap().ejected_until.filter(|until| *until > now)
+    }
+}
+
+/// A request in flight to an endpoint, counted until dropped (even if its future is, mid-request).
+struct InFlight<'a>(&'a AtomicUsize);
+
+impl<'a> InFlight<'a> {
+    fn start(in_flight: &'a AtomicUsize) -> Self {
+        in_flight.fetch_add(1, Ordering::Relaxed);
+        Self(in_flight)
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Pool of RPC node endpoints. Each request is sent to one endpoint, picked as per the
+/// `PoolStrategy` among those not ejected. An endpoint failing with a transient error is ejected
+/// for `EJECT_DURATION_SECS`.
+///
+/// Subscriptions stick to the first endpoint supporting them, as subscription ids are only known
+/// to the node that issued them.
+#[derive(Debug, Clone)]
+pub(crate) struct RpcPool {
+    endpoints: Arc<Vec<Endpoint>>,
+    strategy: PoolStrategy,
+    next: Arc<AtomicUsize>,
+}
+
+impl RpcPool {
+    pub(crate) fn new(endpoints: Vec<(String, Transport)>, strategy: PoolStrategy) -> Self {
+        let endpoints = endpoints
+            .into_iter()
+            .map(|(url, transport)| Endpoint {
+                url,
+                transport,
+                in_flight: AtomicUsize::new(0),
+                stats: Mutex::new(EndpointStats::default()),
+            })
+            .collect();
+
+        Self { endpoints: Arc::new(endpoints), strategy, next: Arc::new(AtomicUsize::new(0)) }
+    }
+
+    /// Whether any endpoint supports `eth_subscribe`.
+    pub(crate) fn is_pubsub(&self) -> bool {
+        self.pubsub_endpoint().is_some()
+    }
+
+    /// The endpoint used for subscriptions.
+    fn pubsub_endpoint(&self) -> Option<&Endpoint> {
+        self.endpoints.iter().find(|endpoint| endpoint.transport.is_pubsub())
+    }
+
+    /// Pick an endpoint for the next request. If all of them are ejected, the one to be back the
+    /// soonest is picked.
+    fn pick(&self) -> &Endpoint {
+        let now = Instant::now();
+        let available: Vec<&Endpoint> = self
+            .endpoints
+            .iter()
+            .filter(|endpoint| endpoint.ejected_until(now).is_none())
+            .collect();
+        if available.is_empty() {
+            return self
+                .endpoints
+                .iter()
+                .min_by_key(|endpoint| endpoint.ejected_until(now))
+                .expect("RPC pool has no endpoints");
+        }
+
+        match self.strategy {
+            PoolStrategy::RoundRobin => {
+                available[self.next.fetch_add(1, Ordering::Relaxed) % available.len()]
+            }
+            PoolStrategy::LeastInFlight => available
+                .into_iter()
+                .min_by_key(|endpoint| endpoint.in_flight.load(Ordering::Relaxed))
+                .expect("non-empty"),
+        }
+    }
+}
+
+#[async_trait]
+impl JsonRpcClient for RpcPool {
+    type Error = ProviderError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        let endpoint = match method {
+            "eth_subscribe" | "eth_unsubscribe" => self.pubsub_endpoint().ok_or_else(|| {
+                ProviderError::CustomError("No RPC endpoint supports subscriptions".into())
+            })?,
+            _ => self.pick(),
+        };
+
+        let in_flight = InFlight::start(&endpoint.in_flight);
+        let started = Instant::now();
+        let res = JsonRpcClient::request(&endpoint.transport, method, params).await;
+        let latency = started.elapsed();
+        drop(in_flight);
+
+        let mut stats = endpoint.stats.lock().unwrap();
+        stats.requests += 1;
+        stats.total_latency += latency;
+        if let Err(err) = &res {
+            // only the node's health matters here, not e.g. a reverted call
+            if RpcErrorClass::classify(err).is_transient() {
+                stats.failures += 1;
+                stats.ejections += 1;
+                stats.ejected_until =
+                    Some(Instant::now() + Duration::from_secs(EJECT_DURATION_SECS));
+                log::debug!("Ejecting RPC endpoint \'{}\' due to: {}", endpoint.url, err);
+            }
+        }
+
+        res
+    }
+}
+
+impl PubsubClient for RpcPool {
+    type NotificationStream = mpsc::UnboundedReceiver<Box<RawValue>>;
+
+    fn subscribe<T: Into<U256>>(&self, id: T) -> Result<Self::NotificationStream, Self::Error> {
+        match self.pubsub_endpoint() {
+            Some(endpoint) => endpoint.transport.subscribe(id),
+            None => {
+                Err(ProviderError::CustomError("No RPC endpoint supports subscriptions".into()))
+            }
+        }
+    }
+
+    fn unsubscribe<T: Into<U256>>(&self, id: T) -> Result<(), Self::Error> {
+        match self.pubsub_endpoint() {
+            Some(endpoint) => endpoint.transport.unsubscribe(id),
+            None => {
+                Err(ProviderError::CustomError("No RPC endpoint supports subscriptions".into()))
+            }
+        }
+    }
+}
+
+impl fmt::Display for RpcPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let now = Instant::now();
+        writeln!(
+            f,
+            "{:<50} {:>10} {:>10} {:>16} {:>10} {:>8}",
+            "RPC endpoint", "requests", "failures", "avg. latency", "ejections", "status"
+        )?;
+        for endpoint in self.endpoints.iter() {
+            let stats = endpoint.stats.lock().unwrap();
+            let avg_latency =
+                stats.total_latency / u32::try_from(stats.requests.max(1)).unwrap_or(u32::MAX);
+            let ejected = stats.ejected_until.is_some_and(|until| until > now);
+            let status = if ejected { "ejected" } else { "ok" };
+            writeln!(
+                f,
+                "{:<50} {:>10} {:>10} {:>16} {:>10} {:>8}",
+                endpoint.url,
+                stats.requests,
+                stats.failures,
+                format!("{:.2?}", avg_latency),
+                stats.ejections,
+                status
+            )?;
+        }
+
+        Ok(())
+    }
+}
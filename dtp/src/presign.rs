@@ -0,0 +1,296 @@
+use crate::{
+    batch::{poll_receipts, BatchClient},
+    compute::Compute,
+    fees::TxFees,
+    transport::Client,
+    utils::{wait_for_receipt, Senders},
+};
+use bindings::{counter::IncrementCall, load::SetArrayCall};
+use ethers::{
+    abi::AbiEncode, prelude::*, types::transaction::eip2718::TypedTransaction, utils::keccak256,
+};
+use futures::future::join_all;
+use std::{
+    collections::BTreeSet,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Margin (in %) added over the estimated gas, which is fixed for all the pre-signed txs.
+const PRESIGN_GAS_MARGIN_PERCENT: u64 = 10;
+
+/// Call made by each pre-signed tx (or packed into the `aggregate3` txs), with its calldata
+/// pre-encoded from the contract bindings.
+pub(crate) struct PresignedCall {
+    pub(crate) to: Address,
+    pub(crate) data: Bytes,
+    pub(crate) name: String,
+    /// Storage slots of `to` touched by the call, as known from the contract's storage layout
+    /// (i.e. its static access list).
+    pub(crate) slots: Vec<H256>,
+}
+
+impl PresignedCall {
+    /// `Counter::increment()`
+    pub(crate) fn counter_increment(counter_address: Address) -> Self {
+        Self {
+            to: counter_address,
+            data: IncrementCall.encode().into(),
+            name: "Counter::increment()".to_string(),
+            // `number`
+            slots: vec![H256::zero()],
+        }
+    }
+
+    /// `Load::setArray(count)`
+    pub(crate) fn load_set_array(load_address: Address, count: u16) -> Self {
+        // the length of `arr1` (at slot 0), and its first `count` elements, stored from
+        // `keccak256(0)` on. The elements beyond (if `arr1` was longer) are cleared as well.
+        let first = U256::from(keccak256(H256::zero()));
+        let mut slots = vec![H256::zero()];
+        slots.extend((0..count).map(|i| H256::from_uint(&(first + i))));
+
+        Self {
+            to: load_address,
+            data: SetArrayCall { count: count.into() }.encode().into(),
+            name: format!("Load::setArray({})", count),
+            slots,
+        }
+    }
+
+    /// `compute`'s `aggregate3` call, made to `Multicall3`
+    pub(crate) fn compute(compute: &Compute) -> Self {
+        Self {
+            to: compute.multicall_address,
+            data: compute.data(),
+            name: compute.name(),
+            // the only slot written is `Counter`'s, not `Multicall3`'s
+            slots: Vec::new(),
+        }
+    }
+}
+
+/// A tx signed offline, ready to be sent via `eth_sendRawTransaction`.
+pub(crate) struct SignedTx {
+    pub(crate) hash: TxHash,
+    pub(crate) raw: Bytes,
+    pub(crate) from: Address,
+    pub(crate) nonce: U256,
+}
+
+/// Phase 1: build & sign the txs of all the senders offline.
+///
+/// The gas is estimated once (from the first sender) and fixed for all the txs, the `fees` are
+/// resolved once by the caller, and the nonces are handed out by the senders' local nonce manager.
+/// So, no RPC request is made per tx.
+pub(crate) async fn presign_txs(
+    client: Arc<Client>,
+    senders: &Senders,
+    call: &PresignedCall,
+    fees: &TxFees,
+) -> eyre::Result<Vec<SignedTx>> {
+    let first_sender = senders.signers.first().ok_or_else(|| eyre::eyre!("No senders"))?;
+    let estimate_tx: TypedTransaction = TransactionRequest::new()
+        .from(first_sender.address())
+        .to(call.to)
+        .data(call.data.clone())
+        .into();
+    let gas = client.estimate_gas(&estimate_tx, None).await? * (100 + PRESIGN_GAS_MARGIN_PERCENT)
+        / 100_u64;
+
+    let started = Instant::now();
+    let signed_txs = (0..senders.num_txs())
+        .map(|tx| {
+            let signer = senders.signer(tx).clone().with_chain_id(senders.chain_id);
+            let nonce = senders.nonces.next(signer.address())?;
+            let mut tx: TypedTransaction = TransactionRequest::new()
+                .from(signer.address())
+                .to(call.to)
+                .data(call.data.clone())
+                .gas(gas)
+                .nonce(nonce)
+                .chain_id(senders.chain_id)
+                .into();
+            fees.apply(&mut tx);
+            let signature = signer.sign_transaction_sync(&tx)?;
+            let raw = tx.rlp_signed(&signature);
+
+            Ok(SignedTx { hash: H256::from(keccak256(&raw)), raw, from: signer.address(), nonce })
+        })
+        .collect::<eyre::Result<Vec<_>>>()?;
+    println!(
+        "Pre-signed {} \'{}\' txs (gas: {}, {}) in {:.2?}.",
+        signed_txs.len(),
+        call.name,
+        gas,
+        fees,
+        started.elapsed()
+    );
+
+    Ok(signed_txs)
+}
+
+/// Phase 2: push the raw txs via `eth_sendRawTransaction` as fast as the transport allows (in
+/// chunks of `MAX_BATCH_SIZE` requests in flight, or in JSON-RPC batches via `batch_client`), w/o
+/// waiting for any receipt.
+///
+/// Returns the hashes of the txs accepted by the node, along with the time it took to send all
+/// of them.
+pub(crate) async fn blast_txs(
+    client: Arc<Client>,
+    batch_client: Option<&BatchClient>,
+    signed_txs: Vec<SignedTx>,
+    max_batch_size: u16,
+) -> eyre::Result<(Vec<TxHash>, Duration)> {
+    let started = Instant::now();
+    let results: Vec<eyre::Result<TxHash>> = match batch_client {
+        Some(batch_client) => {
+            let raw_txs: Vec<Bytes> =
+                signed_txs.iter().map(|signed_tx| signed_tx.raw.clone()).collect();
+            let results = batch_client.send_raw_transactions(&raw_txs).await?;
+            results.into_iter().map(|res| Ok(res?)).collect()
+        }
+        None => {
+            let mut results = Vec::with_capacity(signed_txs.len());
+            for chunk in signed_txs.chunks(max_batch_size.into()) {
+                let batch = chunk
+                    .iter()
+                    .map(|signed_tx| client.send_raw_transaction(signed_tx.raw.clone()));
+                results.extend(join_all(batch).await.into_iter().map(|res| Ok(res?.tx_hash())));
+            }
+            results
+        }
+    };
+    let ingest_time = started.elapsed();
+
+    let mut accepted = Vec::with_capacity(signed_txs.len());
+    let mut num_rejected = 0;
+    for (signed_tx, res) in signed_txs.iter().zip(results) {
+        match res {
+            Ok(tx_hash) => accepted.push(tx_hash),
+            Err(e) => {
+                num_rejected += 1;
+                log::debug!("tx {:?} rejected: {}", signed_tx.hash, e);
+            }
+        }
+    }
+
+    println!(
+        "Ingest: {} txs accepted, {} rejected in {:.2?} ({:.2} tx/s)",
+        accepted.len(),
+        num_rejected,
+        ingest_time,
+        accepted.len() as f64 / ingest_time.as_secs_f64()
+    );
+
+    Ok((accepted, ingest_time))
+}
+
+/// Await the receipts of the blasted txs (in chunks of `MAX_BATCH_SIZE`, or polled in JSON-RPC
+/// batches via `batch_client`) and report the inclusion rate, measured over the timestamps of the
+/// blocks they landed in.
+///
+/// A rejected tx leaves a nonce gap, so the later txs of its sender are never included. Their
+/// receipts are thus only awaited until `inclusion_timeout` elapses.
+pub(crate) async fn report_inclusion(
+    client: Arc<Client>,
+    batch_client: Option<&BatchClient>,
+    tx_hashes: Vec<TxHash>,
+    max_batch_size: u16,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let receipts = match batch_client {
+        Some(batch_client) => poll_receipts(batch_client, &tx_hashes, inclusion_timeout).await,
+        None => {
+            let deadline = tokio::time::Instant::now() + inclusion_timeout;
+            let mut receipts = Vec::with_capacity(tx_hashes.len());
+            for chunk in tx_hashes.chunks(max_batch_size.into()) {
+                let batch = chunk.iter().map(|tx_hash| {
+                    tokio::time::timeout_at(deadline, wait_for_receipt(client.clone(), *tx_hash))
+                });
+                for (tx_hash, tx_receipt) in chunk.iter().zip(join_all(batch).await) {
+                    match tx_receipt {
+                        Ok(Ok(tx_receipt)) => receipts.push(tx_receipt),
+                        Ok(Err(e)) => log::debug!("{}", e),
+                        Err(_) => log::debug!(
+                            "tx {:?} not included within {:?}",
+                            tx_hash,
+                            inclusion_timeout
+                        ),
+                    }
+                }
+            }
+            receipts
+        }
+    };
+    let block_numbers: Vec<U64> =
+        receipts.iter().map(|tx_receipt| tx_receipt.block_number.unwrap_or_default()).collect();
+
+    report_inclusion_rate(client, tx_hashes.len(), &block_numbers).await
+}
+
+/// Report the inclusion rate of the `num_accepted` txs, out of which those included landed in
+/// `block_numbers` (one per tx), measured over the timestamps of these blocks.
+pub(crate) async fn report_inclusion_rate(
+    client: Arc<Client>,
+    num_accepted: usize,
+    block_numbers: &[U64],
+) -> eyre::Result<()> {
+    let num_included = block_numbers.len();
+    let num_failed = num_accepted - num_included;
+    let blocks: BTreeSet<U64> = block_numbers.iter().copied().collect();
+
+    let (Some(first), Some(last)) = (blocks.first(), blocks.last()) else {
+        println!("Inclusion: none of the {} accepted txs got included", num_accepted);
+        return Ok(());
+    };
+
+    // measure from the parent of the first block, as its txs were accepted after that one
+    let timestamp = |block_number: U64| {
+        let client = client.clone();
+        async move {
+            let block = client
+                .get_block(block_number)
+                .await?
+                .ok_or_else(|| eyre::eyre!("Failed to get block #{}", block_number))?;
+            Ok::<_, eyre::Report>(block.timestamp)
+        }
+    };
+    let span_secs =
+        (timestamp(*last).await? - timestamp(first.saturating_sub(U64::one())).await?).as_u64();
+    let num_blocks = (last - first).as_u64() + 1;
+
+    println!(
+        "Inclusion: {} txs included, {} not, in blocks #{}..=#{} ({:.2} tx/block, {:.2} tx/s)",
+        num_included,
+        num_failed,
+        first,
+        last,
+        num_included as f64 / num_blocks as f64,
+        num_included as f64 / span_secs.max(1) as f64
+    );
+
+    Ok(())
+}
+
+/// Pre-sign then blast the senders' txs making `call`, and report the ingest rate separately
+/// from the inclusion rate.
+///
+/// With a `batch_client`, the txs are sent & their receipts polled in JSON-RPC batches.
+pub(crate) async fn presign_and_blast(
+    client: Arc<Client>,
+    batch_client: Option<BatchClient>,
+    senders: Arc<Senders>,
+    call: PresignedCall,
+    max_batch_size: u16,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    let fees = senders.fees.resolve(client.as_ref()).await?;
+    let signed_txs = presign_txs(client.clone(), senders.as_ref(), &call, &fees).await?;
+    let (tx_hashes, _) =
+        blast_txs(client.clone(), batch_client.as_ref(), signed_txs, max_batch_size).await?;
+    report_inclusion(client, batch_client.as_ref(), tx_hashes, max_batch_size, inclusion_timeout)
+        .await?;
+
+    Ok(())
+}
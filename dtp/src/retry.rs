@@ -0,0 +1,297 @@
+use crate::pool::RpcPool;
+use async_trait::async_trait;
+use ethers::{prelude::*, utils::keccak256};
+use futures::channel::mpsc;
+use rand::Rng;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::value::RawValue;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Debug},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Upper bound (in ms) of the delay between 2 retries of a request.
+const MAX_BACKOFF_MS: u64 = 10_000;
+
+/// Class of an RPC failure, as reported by the node or the transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum RpcErrorClass {
+    /// The node throttles us e.g. "Too many connections. Please try again later."
+    RateLimited,
+    /// The connection to the node got reset/closed or timed out.
+    ConnectionReset,
+    /// The sender's nonce is already used.
+    NonceTooLow,
+    /// The tx's gas price is too low to be accepted (or to replace a pending tx).
+    Underpriced,
+    /// The tx's gas limit exceeds the block gas limit.
+    ExceedsBlockGasLimit,
+    /// The call/tx reverted e.g. during gas estimation.
+    Revert,
+    /// Any other failure.
+    Other,
+}
+
+impl RpcErrorClass {
+    /// Classify an RPC failure.
+    pub(crate) fn classify(err: &ProviderError) -> Self {
+        Self::classify_msg(&err.to_string())
+    }
+
+    /// Classify an RPC failure from its message.
+    ///
+    /// A 429 is only matched as an HTTP status (`HTTP 429 ...` of a batch) or as a JSON-RPC error
+    /// code (`code: 429, ...`), as it could be part of e.g. a tx hash or an amount otherwise.
+    pub(crate) fn classify_msg(msg: &str) -> Self {
+        let msg = msg.to_lowercase();
+
+        if msg.contains("too many connections")
+            || msg.contains("too many requests")
+            || msg.contains("rate limit")
+            || msg.contains("http 429")
+            || msg.contains("code: 429,")
+        {
+            RpcErrorClass::RateLimited
+        } else if msg.contains("connection reset")
+            || msg.contains("connection closed")
+            || msg.contains("connection refused")
+            || msg.contains("broken pipe")
+            || msg.contains("closed unexpectedly")
+            || msg.contains("error sending request")
+            || msg.contains("timed out")
+        {
+            RpcErrorClass::ConnectionReset
+        } else if msg.contains("nonce too low") {
+            RpcErrorClass::NonceTooLow
+        } else if msg.contains("underpriced") {
+            RpcErrorClass::Underpriced
+        } else if msg.contains("exceeds block gas limit") {
+            RpcErrorClass::ExceedsBlockGasLimit
+        } else if msg.contains("revert") {
+            RpcErrorClass::Revert
+        } else {
+            RpcErrorClass::Other
+        }
+    }
+
+    /// Whether a request failing with this class is worth retrying as is.
+    pub(crate) fn is_transient(&self) -> bool {
+        matches!(self, RpcErrorClass::RateLimited | RpcErrorClass::ConnectionReset)
+    }
+}
+
+/// Hash of `raw_tx` if it failed to be sent w/ `msg` because the node already has it in its pool.
+pub(crate) fn already_known_tx_hash(msg: &str, raw_tx: &[u8]) -> Option<TxHash> {
+    let msg = msg.to_lowercase();
+    (msg.contains("already known") || msg.contains("known transaction"))
+        .then(|| H256::from(keccak256(raw_tx)))
+}
+
+/// Retry policy for transient RPC failures.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RetryPolicy {
+    /// Max. no. of retries of a request, after its first attempt.
+    pub(crate) max_retries: u32,
+    /// Base delay (in ms) of the exponential backoff.
+    pub(crate) backoff_ms: u64,
+}
+
+impl RetryPolicy {
+    /// Delay before the `attempt`-th retry (0-based): exponential backoff capped at
+    /// `MAX_BACKOFF_MS`, with a random jitter of up to half of it.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let backoff_ms =
+            self.backoff_ms.saturating_mul(1 << attempt.min(16)).clamp(1, MAX_BACKOFF_MS);
+        let jittered_ms = rand::thread_rng().gen_range(backoff_ms / 2..=backoff_ms);
+
+        Duration::from_millis(jittered_ms)
+    }
+}
+
+/// No. of retries & failures (i.e. not retried or retries exhausted) of a class.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct RetryClassStats {
+    pub(crate) retries: u64,
+    pub(crate) failures: u64,
+}
+
+/// Retry stats per class of RPC failure.
+#[derive(Debug, Default)]
+pub(crate) struct RetryStats(Mutex<BTreeMap<RpcErrorClass, RetryClassStats>>);
+
+impl RetryStats {
+    pub(crate) fn record_retry(&self, class: RpcErrorClass) {
+        self.0.lock().unwrap().entry(class).or_default().retries += 1;
+    }
+
+    pub(crate) fn record_failure(&self, class: RpcErrorClass) {
+        self.0.lock().unwrap().entry(class).or_default().failures += 1;
+    }
+}
+
+impl fmt::Display for RetryStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stats = self.0.lock().unwrap();
+        if stats.is_empty() {
+            return writeln!(f, "No RPC failures.");
+        }
+
+        writeln!(f, "{:<22} {:>10} {:>10}", "RPC failure class", "retries", "failures")?;
+        for (class, class_stats) in stats.iter() {
+            writeln!(
+                f,
+                "{:<22} {:>10} {:>10}",
+                format!("{:?}", class),
+                class_stats.retries,
+                class_stats.failures
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Transport that retries requests failing with a transient RPC error (see
+/// `RpcErrorClass::is_transient`) with jittered exponential backoff, and keeps per-class stats.
+#[derive(Debug, Clone)]
+pub(crate) struct RetryTransport {
+    inner: RpcPool,
+    policy: RetryPolicy,
+    stats: Arc<RetryStats>,
+}
+
+impl RetryTransport {
+    pub(crate) fn new(inner: RpcPool, policy: RetryPolicy) -> Self {
+        Self { inner, policy, stats: Arc::new(RetryStats::default()) }
+    }
+
+    /// Whether the underlying transport supports `eth_subscribe`.
+    pub(crate) fn is_pubsub(&self) -> bool {
+        self.inner.is_pubsub()
+    }
+
+    /// The underlying pool of RPC endpoints.
+    pub(crate) fn pool(&self) -> &RpcPool {
+        &self.inner
+    }
+
+    /// Retry stats per class of RPC failure.
+    pub(crate) fn stats(&self) -> Arc<RetryStats> {
+        self.stats.clone()
+    }
+}
+
+#[async_trait]
+impl JsonRpcClient for RetryTransport {
+    type Error = ProviderError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        // serialize once, so that the params can be re-sent on each attempt
+        let params = serde_json::to_value(params)?;
+
+        let mut attempt = 0;
+        loop {
+            let err = match JsonRpcClient::request(&self.inner, method, &params).await {
+                Ok(res) => return Ok(res),
+                Err(err) => err,
+            };
+
+            // A retried raw tx might have reached the node on the previous attempt. In that case,
+            // the tx is already in the node's pool, so return its hash.
+            if attempt > 0 && method == "eth_sendRawTransaction" {
+                if let Some(tx_hash) = params
+                    .get(0)
+                    .and_then(|raw_tx| serde_json::from_value::<Bytes>(raw_tx.clone()).ok())
+                    .and_then(|raw_tx| already_known_tx_hash(&err.to_string(), &raw_tx))
+                {
+                    return Ok(serde_json::from_value(serde_json::to_value(tx_hash)?)?);
+                }
+            }
+
+            let class = RpcErrorClass::classify(&err);
+            if !class.is_transient() || attempt >= self.policy.max_retries {
+                self.stats.record_failure(class);
+                return Err(err);
+            }
+
+            self.stats.record_retry(class);
+            log::debug!("Retrying \'{}\' after {:?} failure: {}", method, class, err);
+            tokio::time::sleep(self.policy.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+}
+
+impl PubsubClient for RetryTransport {
+    type NotificationStream = mpsc::UnboundedReceiver<Box<RawValue>>;
+
+    fn subscribe<T: Into<U256>>(&self, id: T) -> Result<Self::NotificationStream, Self::Error> {
+        self.inner.subscribe(id)
+    }
+
+    fn unsubscribe<T: Into<U256>>(&self, id: T) -> Result<(), Self::Error> {
+        self.inner.unsubscribe(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_msg() {
+        let cases = [
+            ("Too many connections. Please try again later.", RpcErrorClass::RateLimited),
+            ("HTTP 429 Too Many Requests: <html>", RpcErrorClass::RateLimited),
+            (
+                "Deserialization Error: EOF. Response: 429 Too Many Requests",
+                RpcErrorClass::RateLimited,
+            ),
+            ("(code: 429, message: exceeded quota, data: None)", RpcErrorClass::RateLimited),
+            (
+                "(code: -32005, message: rate limit exceeded, data: None)",
+                RpcErrorClass::RateLimited,
+            ),
+            (
+                "error sending request for url (http://127.0.0.1:8545/)",
+                RpcErrorClass::ConnectionReset,
+            ),
+            ("connection reset by peer", RpcErrorClass::ConnectionReset),
+            ("operation timed out", RpcErrorClass::ConnectionReset),
+            ("(code: -32000, message: nonce too low, data: None)", RpcErrorClass::NonceTooLow),
+            ("replacement transaction underpriced", RpcErrorClass::Underpriced),
+            ("exceeds block gas limit", RpcErrorClass::ExceedsBlockGasLimit),
+            ("execution reverted: Counter: overflow", RpcErrorClass::Revert),
+            // a 429 that's neither an HTTP status nor an error code
+            ("known transaction: 0x4290ab", RpcErrorClass::Other),
+            ("insufficient funds for gas * price + value: balance 14290", RpcErrorClass::Other),
+            (
+                "(code: -32000, message: tx 429 of the pool is full, data: None)",
+                RpcErrorClass::Other,
+            ),
+            ("", RpcErrorClass::Other),
+        ];
+        for (msg, class) in cases {
+            assert_eq!(RpcErrorClass::classify_msg(msg), class, "{}", msg);
+        }
+    }
+
+    #[test]
+    fn already_known_tx_hash() {
+        let raw_tx = [0x02, 0xf8, 0x6c];
+        let tx_hash = H256::from(keccak256(raw_tx));
+
+        assert_eq!(super::already_known_tx_hash("already known", &raw_tx), Some(tx_hash));
+        assert_eq!(
+            super::already_known_tx_hash("Known transaction: 0x12ab", &raw_tx),
+            Some(tx_hash)
+        );
+        assert_eq!(super::already_known_tx_hash("nonce too low", &raw_tx), None);
+    }
+}
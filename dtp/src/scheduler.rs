@@ -0,0 +1,235 @@
+use crate::{contracts::log_tx_dbg, transport::Client, utils::wait_for_receipt};
+use ethers::prelude::*;
+use futures::{
+    future::{self, join_all},
+    stream::{self, BoxStream},
+    StreamExt,
+};
+use std::{collections::BTreeSet, future::Future, ops::Range, sync::Arc, time::Duration};
+
+/// Interval (in ms) at which the latest block number is polled while waiting for a new block.
+const BLOCK_POLL_INTERVAL_MS: u64 = 500;
+
+/// Split `num_txs` txs into exactly `num_blocks` consecutive slices, one per block.
+///
+/// Slot `i` gets the txs in `[ceil(i * a / b), ceil((i + 1) * a / b))`, which covers the 3
+/// cases:
+/// 1. num_txs < num_blocks: one tx each in evenly spaced blocks, the rest stay empty.
+/// 2. num_txs = num_blocks: one tx per block.
+/// 3. num_txs > num_blocks: slices differ in size by at most 1 tx.
+pub(crate) fn get_block_slices(num_txs: usize, num_blocks: usize) -> Vec<Range<usize>> {
+    let start = |i: usize| (i * num_txs).div_ceil(num_blocks);
+    (0..num_blocks).map(|i| start(i)..start(i + 1)).collect()
+}
+
+/// Pack txs into per-block bins based on their (estimated) `gas`, s.t. the total gas of each bin
+/// stays within `block_gas_limit`. Returns the tx indices of each bin.
+///
+/// It's a first-fit decreasing packing i.e. the heaviest txs are placed first, each into the
+/// first bin that still has room for it.
+pub(crate) fn pack_into_block_bins(
+    gas: &[U256],
+    block_gas_limit: U256,
+) -> eyre::Result<Vec<Vec<usize>>> {
+    let mut order: Vec<usize> = (0..gas.len()).collect();
+    order.sort_by(|a, b| gas[*b].cmp(&gas[*a]));
+
+    let mut bins: Vec<(U256, Vec<usize>)> = Vec::new();
+    for i in order {
+        eyre::ensure!(
+            gas[i] <= block_gas_limit,
+            "tx #{} needs {} gas, which exceeds the block gas limit of {}",
+            i,
+            gas[i],
+            block_gas_limit
+        );
+
+        match bins.iter_mut().find(|(used, _)| *used + gas[i] <= block_gas_limit) {
+            Some((used, txs)) => {
+                *used += gas[i];
+                txs.push(i);
+            }
+            None => bins.push((gas[i], vec![i])),
+        }
+    }
+
+    Ok(bins.into_iter().map(|(_, txs)| txs).collect())
+}
+
+/// Get the gas limit of the latest block.
+pub(crate) async fn get_block_gas_limit(client: Arc<Client>) -> eyre::Result<U256> {
+    let block = client
+        .get_block(BlockNumber::Latest)
+        .await?
+        .ok_or_else(|| eyre::eyre!("Failed to get the latest block"))?;
+
+    Ok(block.gas_limit)
+}
+
+/// Stream of new block heads' numbers.
+///
+/// Over a WebSocket transport, it's backed by `eth_subscribe("newHeads")`. Over HTTP, the latest
+/// block number is polled every `BLOCK_POLL_INTERVAL_MS` and only increasing numbers are yielded.
+pub(crate) async fn new_heads(client: &Client) -> eyre::Result<BoxStream<'_, eyre::Result<U64>>> {
+    if client.as_ref().is_pubsub() {
+        let heads = client
+            .subscribe_blocks()
+            .await?
+            .filter_map(|block| future::ready(block.number.map(Ok)));
+
+        return Ok(heads.boxed());
+    }
+
+    let heads = stream::unfold(U64::zero(), move |last| async move {
+        loop {
+            match client.get_block_number().await {
+                Ok(block_number) if block_number > last => {
+                    return Some((Ok(block_number), block_number))
+                }
+                Ok(_) => tokio::time::sleep(Duration::from_millis(BLOCK_POLL_INTERVAL_MS)).await,
+                Err(e) => return Some((Err(e.into()), last)),
+            }
+        }
+    });
+
+    Ok(heads.boxed())
+}
+
+/// Wait until a block newer than `after` is produced and return its number.
+pub(crate) async fn wait_for_new_block(
+    heads: &mut BoxStream<'_, eyre::Result<U64>>,
+    after: U64,
+) -> eyre::Result<U64> {
+    while let Some(block_number) = heads.next().await {
+        let block_number = block_number?;
+        if block_number > after {
+            return Ok(block_number);
+        }
+    }
+
+    eyre::bail!("Stream of new block heads ended unexpectedly")
+}
+
+/// Send the txs of each slot right after a new block head is seen, so that each slot is released
+/// only once the previous block has been produced. The txs of a slot are sent in chunks of
+/// `MAX_BATCH_SIZE` via `send`, which gets the tx index.
+///
+/// Receipts are not awaited here, so that waiting on them doesn't push the next slot into a later
+/// block. Returns the block number each slot was sent after, along with its tx hashes.
+pub(crate) async fn send_in_blocks<F, Fut>(
+    client: Arc<Client>,
+    slots: Vec<Vec<usize>>,
+    max_batch_size: u16,
+    send: F,
+) -> eyre::Result<Vec<(U64, Vec<TxHash>)>>
+where
+    F: Fn(usize) -> Fut,
+    Fut: Future<Output = eyre::Result<TxHash>>,
+{
+    let mut heads = new_heads(client.as_ref()).await?;
+    let mut block_number = client.get_block_number().await?;
+    let mut sent_slots = Vec::with_capacity(slots.len());
+
+    for (i, slot) in slots.into_iter().enumerate() {
+        // wait for the next block head, so that each slot goes into its own block
+        block_number = wait_for_new_block(&mut heads, block_number).await?;
+
+        let mut tx_hashes = Vec::with_capacity(slot.len());
+        for chunk in slot.chunks(max_batch_size.into()) {
+            for tx_hash in join_all(chunk.iter().map(|&tx| send(tx))).await {
+                tx_hashes.push(tx_hash?);
+            }
+        }
+        println!("Slot #{}: sent {} txs after block #{}", i, tx_hashes.len(), block_number);
+
+        sent_slots.push((block_number, tx_hashes));
+    }
+
+    Ok(sent_slots)
+}
+
+/// Await the receipts of the txs sent via `send_in_blocks` and report the block(s) each slot
+/// actually landed in, along with the gas used by the slot.
+pub(crate) async fn report_sent_slots(
+    client: Arc<Client>,
+    sent_slots: Vec<(U64, Vec<TxHash>)>,
+    contract_name: &str,
+) -> eyre::Result<()> {
+    for (i, (sent_after, tx_hashes)) in sent_slots.into_iter().enumerate() {
+        if tx_hashes.is_empty() {
+            println!("Slot #{}: no txs (sent after block #{})", i, sent_after);
+            continue;
+        }
+
+        let num_txs = tx_hashes.len();
+        let receipts = join_all(
+            tx_hashes.into_iter().map(|tx_hash| wait_for_receipt(client.clone(), tx_hash)),
+        )
+        .await;
+
+        let mut landed_in = BTreeSet::new();
+        let mut gas_used = U256::zero();
+        for tx_receipt in receipts {
+            let tx_receipt = tx_receipt?;
+            landed_in.insert(tx_receipt.block_number.unwrap_or_default().as_u64());
+            gas_used += tx_receipt.gas_used.unwrap_or_default();
+            log_tx_dbg(tx_receipt, contract_name);
+        }
+        println!(
+            "Slot #{}: {} txs sent after block #{}, used {} gas, landed in block(s) {:?}",
+            i, num_txs, sent_after, gas_used, landed_in
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_block_slices_spreads_txs_over_all_blocks() {
+        // fewer txs than blocks: evenly spaced, the rest stay empty
+        assert_eq!(get_block_slices(2, 4), vec![0..1, 1..1, 1..2, 2..2]);
+        // as many txs as blocks
+        assert_eq!(get_block_slices(3, 3), vec![0..1, 1..2, 2..3]);
+        // more txs than blocks: the sizes differ by at most 1, rounded up
+        assert_eq!(get_block_slices(7, 3), vec![0..3, 3..5, 5..7]);
+        assert_eq!(get_block_slices(0, 2), vec![0..0, 0..0]);
+
+        for (num_txs, num_blocks) in [(1, 1), (5, 8), (10, 3), (1000, 7)] {
+            let slices = get_block_slices(num_txs, num_blocks);
+            assert_eq!(slices.len(), num_blocks);
+            assert_eq!(slices.first().unwrap().start, 0);
+            assert_eq!(slices.last().unwrap().end, num_txs);
+            assert!(slices.windows(2).all(|pair| pair[0].end == pair[1].start));
+            let sizes: Vec<usize> = slices.iter().map(|slice| slice.len()).collect();
+            assert!(sizes.iter().max().unwrap() - sizes.iter().min().unwrap() <= 1);
+        }
+    }
+
+    #[test]
+    fn pack_into_block_bins_fits_the_heaviest_first() {
+        let gas: Vec<U256> = [30, 70, 50, 50, 20].into_iter().map(U256::from).collect();
+        let bins = pack_into_block_bins(&gas, 100.into()).unwrap();
+
+        assert_eq!(bins, vec![vec![1, 0], vec![2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn pack_into_block_bins_fills_up_to_the_limit() {
+        let gas = vec![U256::from(100); 3];
+        assert_eq!(
+            pack_into_block_bins(&gas, 100.into()).unwrap(),
+            vec![vec![0], vec![1], vec![2]]
+        );
+        assert!(pack_into_block_bins(&[], 100.into()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn pack_into_block_bins_rejects_a_tx_over_the_limit() {
+        let gas: Vec<U256> = [10, 101].into_iter().map(U256::from).collect();
+        assert!(pack_into_block_bins(&gas, 100.into()).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    contracts::{counter_get_number, counter_set_number_send},
+    transport::Client,
+    utils::{run_tracked, Senders},
+};
+use ethers::prelude::*;
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+/// Values written by the SETNUMBER txs.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum SetNumberValues {
+    /// Each tx writes a random value.
+    Random,
+    /// Each tx writes its sender's address (as a number), so the last writer can be told apart.
+    Account,
+}
+
+/// Implement `FromStr` trait for SetNumberValues
+impl FromStr for SetNumberValues {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "random" => Ok(SetNumberValues::Random),
+            "account" => Ok(SetNumberValues::Account),
+            _ => Err(format!("\'{}\' is not a valid SetNumberValues", s)),
+        }
+    }
+}
+
+/// As SETNUMBER transaction type, each new account sends `Counter::setNumber` txs writing either
+/// random values or its own address (see `SetNumberValues`), in batches of `MAX_BATCH_SIZE`.
+///
+/// As the writes overwrite each other, the final `Counter::number()` is then checked to be the
+/// value written by whichever tx landed last in the chain's ordering (block no., then index in the
+/// block).
+pub(crate) async fn multicall_set_number_txs(
+    client: Arc<Client>,
+    counter_address: Address,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    values: SetNumberValues,
+    inclusion_timeout: Duration,
+) -> eyre::Result<()> {
+    // pick the value written by each tx
+    let numbers: Vec<U256> = (0..senders.num_txs())
+        .map(|tx| match values {
+            SetNumberValues::Random => U256::from(H256::random().as_bytes()),
+            SetNumberValues::Account => U256::from(senders.signer(tx).address().as_bytes()),
+        })
+        .collect();
+
+    let txs: Vec<usize> = (0..senders.num_txs()).collect();
+    let (sent, report) = run_tracked(
+        client.clone(),
+        &senders,
+        &txs,
+        max_batch_size,
+        inclusion_timeout,
+        "Counter::setNumber()",
+        |tx, signer, nonce| {
+            counter_set_number_send(
+                client.clone(),
+                counter_address,
+                signer,
+                senders.chain_id,
+                &senders.fees,
+                numbers[tx],
+                nonce,
+            )
+        },
+    )
+    .await?;
+    let writes: Vec<(TxHash, U256)> =
+        sent.into_iter().map(|(tx, tx_hash)| (tx_hash, numbers[tx])).collect();
+
+    // the last write in the chain's ordering is the one expected to be stored
+    let (inclusion, tx_hash, expected) = writes
+        .iter()
+        .filter_map(|(tx_hash, number)| {
+            report.included.get(tx_hash).map(|inclusion| (inclusion, tx_hash, number))
+        })
+        .max_by_key(|(inclusion, _, _)| (inclusion.block_number, inclusion.index))
+        .ok_or_else(|| {
+            eyre::eyre!("No \'Counter::setNumber()\' tx included, so nothing to check")
+        })?;
+    let num_after = counter_get_number(client.clone(), counter_address).await?;
+    println!(
+        "Last \'Counter::setNumber()\' tx: {:?} (block #{}, index {}), wrote: {}",
+        tx_hash, inclusion.block_number, inclusion.index, expected
+    );
+    println!("Number stored in \'Counter\' after the calls: {}", num_after);
+    eyre::ensure!(
+        num_after == *expected,
+        "Number stored in \'Counter\' isn't the one written by the last included tx. Was it written \
+         by other txs meanwhile, or did the last tx revert?"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,326 @@
+use crate::{fees::wei_to_gwei_string, scheduler::new_heads, transport::Client};
+use ethers::prelude::*;
+use futures::{future::join_all, StreamExt};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::task::JoinHandle;
+
+/// Interval (in ms) at which `InclusionTracker::finish` checks whether all the txs are settled.
+const SETTLE_CHECK_INTERVAL_MS: u64 = 500;
+
+/// No. of receipts of the included txs fetched at once.
+const RECEIPT_BATCH_SIZE: usize = 100;
+
+/// No. of the latest scanned blocks whose untracked txs are kept, in case they get tracked later.
+const UNTRACKED_BLOCKS: u64 = 16;
+
+/// Where a tx got included.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Inclusion {
+    pub(crate) block_number: U64,
+    pub(crate) index: usize,
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    /// Tracked txs, along with the instant each was sent at.
+    sent: HashMap<TxHash, Instant>,
+    /// Tracked txs of the scanned blocks.
+    included: HashMap<TxHash, Inclusion>,
+    /// Untracked txs of the last `UNTRACKED_BLOCKS` scanned blocks, as a tx may be included before
+    /// it's tracked. The older ones are dropped, so that the txs of other senders don't pile up.
+    untracked: HashMap<TxHash, Inclusion>,
+    /// No. of txs that failed to be sent.
+    failed: usize,
+    /// Error of the first tx that failed to be sent.
+    failure: Option<String>,
+}
+
+/// Tracker of the inclusion of sent txs, decoupled from sending them.
+///
+/// Sent txs are handed over via `track` w/o awaiting their receipts. Meanwhile, a background task
+/// watches new block heads, fetches each new block with its tx list & matches the hashes,
+/// recording the inclusion block & index of each tx. A tx not included within `timeout` of
+/// being sent is flagged.
+pub(crate) struct InclusionTracker {
+    client: Arc<Client>,
+    state: Arc<Mutex<TrackerState>>,
+    scanner: JoinHandle<eyre::Result<()>>,
+    timeout: Duration,
+}
+
+impl InclusionTracker {
+    /// Start scanning the blocks after the latest one.
+    pub(crate) async fn start(client: Arc<Client>, timeout: Duration) -> eyre::Result<Self> {
+        let state = Arc::new(Mutex::new(TrackerState::default()));
+        let last_scanned = client.get_block_number().await?;
+
+        let scanner = tokio::spawn({
+            let client = client.clone();
+            let state = state.clone();
+            async move {
+                let mut last_scanned = last_scanned;
+                let mut heads = new_heads(client.as_ref()).await?;
+                while let Some(head) = heads.next().await {
+                    // heads may skip blocks (e.g. when polled), so scan all the blocks up to it
+                    let head = head?;
+                    while last_scanned < head {
+                        let block_number = last_scanned + 1;
+                        let block = client
+                            .get_block(block_number)
+                            .await?
+                            .ok_or_else(|| eyre::eyre!("Failed to get block #{}", block_number))?;
+
+                        let mut state = state.lock().unwrap();
+                        for (index, tx_hash) in block.transactions.into_iter().enumerate() {
+                            let inclusion = Inclusion { block_number, index };
+                            if state.sent.contains_key(&tx_hash) {
+                                state.included.insert(tx_hash, inclusion);
+                            } else {
+                                state.untracked.insert(tx_hash, inclusion);
+                            }
+                        }
+                        state.untracked.retain(|_, inclusion| {
+                            inclusion.block_number + UNTRACKED_BLOCKS > block_number
+                        });
+                        last_scanned = block_number;
+                    }
+                }
+
+                eyre::bail!("Stream of new block heads ended unexpectedly")
+            }
+        });
+
+        Ok(Self { client, state, scanner, timeout })
+    }
+
+    /// Track the inclusion of a tx sent right now.
+    pub(crate) fn track(&self, tx_hash: TxHash) {
+        let mut state = self.state.lock().unwrap();
+        state.sent.insert(tx_hash, Instant::now());
+        if let Some(inclusion) = state.untracked.remove(&tx_hash) {
+            state.included.insert(tx_hash, inclusion);
+        }
+    }
+
+    /// Stop tracking a tx that isn't to be awaited (e.g. stuck behind a nonce gap), so that it's
+    /// neither waited for by `finish` nor reported as not included.
+    pub(crate) fn untrack(&self, tx_hash: &TxHash) {
+        let mut state = self.state.lock().unwrap();
+        state.sent.remove(tx_hash);
+        state.included.remove(tx_hash);
+    }
+
+    /// Count a tx that failed to be sent (so isn't tracked) w/ `err`.
+    pub(crate) fn track_failure(&self, err: &eyre::Report) {
+        log::debug!("tx failed to be sent: {}", err);
+        let mut state = self.state.lock().unwrap();
+        state.failed += 1;
+        state.failure.get_or_insert_with(|| err.to_string());
+    }
+
+    /// No. of tracked txs included so far.
+    pub(crate) fn num_included(&self) -> usize {
+        self.state.lock().unwrap().included.len()
+    }
+
+    /// Wait until each tracked tx is either included or flagged (as not included within the
+    /// timeout), then stop scanning and report them, along with the receipts of the included ones
+    /// (e.g. for the effective gas price paid).
+    pub(crate) async fn finish(mut self) -> eyre::Result<TrackerReport> {
+        loop {
+            {
+                let state = self.state.lock().unwrap();
+                let settled = state.sent.iter().all(|(tx_hash, sent_at)| {
+                    state.included.contains_key(tx_hash) || sent_at.elapsed() >= self.timeout
+                });
+                if settled {
+                    break;
+                }
+            }
+
+            tokio::select! {
+                res = &mut self.scanner => {
+                    res??;
+                    eyre::bail!("Block scanner stopped unexpectedly");
+                }
+                _ = tokio::time::sleep(Duration::from_millis(SETTLE_CHECK_INTERVAL_MS)) => {}
+            }
+        }
+        self.scanner.abort();
+
+        let mut report = TrackerReport { timeout: self.timeout, ..Default::default() };
+        {
+            let state = self.state.lock().unwrap();
+            report.failed = state.failed;
+            report.failure = state.failure.clone();
+            for tx_hash in state.sent.keys() {
+                match state.included.get(tx_hash) {
+                    Some(inclusion) => {
+                        log::debug!(
+                            "tx {:?} included in block #{} at index {}",
+                            tx_hash,
+                            inclusion.block_number,
+                            inclusion.index
+                        );
+                        report.included.insert(*tx_hash, *inclusion);
+                    }
+                    None => {
+                        log::debug!("tx {:?} not included within {:?}", tx_hash, self.timeout);
+                        report.not_included.push(*tx_hash);
+                    }
+                }
+            }
+        }
+
+        let included: Vec<TxHash> = report.included.keys().copied().collect();
+        for chunk in included.chunks(RECEIPT_BATCH_SIZE) {
+            let batch = chunk.iter().map(|tx_hash| self.client.get_transaction_receipt(*tx_hash));
+            for (tx_hash, tx_receipt) in chunk.iter().zip(join_all(batch).await) {
+                let tx_receipt = match tx_receipt? {
+                    Some(tx_receipt) => tx_receipt,
+                    None => continue,
+                };
+                let gas_price = tx_receipt.effective_gas_price.unwrap_or_default();
+                let stats = report
+                    .gas_prices
+                    .entry(tx_receipt.transaction_type.unwrap_or_default())
+                    .or_insert(GasPriceStats { min: gas_price, ..Default::default() });
+                stats.txs += 1;
+                stats.min = stats.min.min(gas_price);
+                stats.max = stats.max.max(gas_price);
+                stats.sum += gas_price;
+                report.receipts.insert(*tx_hash, tx_receipt);
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+/// Effective gas prices (in Wei) paid by the included txs of a tx type.
+#[derive(Debug, Default)]
+pub(crate) struct GasPriceStats {
+    pub(crate) txs: usize,
+    pub(crate) min: U256,
+    pub(crate) max: U256,
+    pub(crate) sum: U256,
+}
+
+/// Outcome of included txs, as per their receipts.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ReceiptStats {
+    pub(crate) included: usize,
+    pub(crate) reverted: usize,
+    /// Gas used by the succeeded txs.
+    pub(crate) gas_used: U256,
+}
+
+impl ReceiptStats {
+    fn add(&mut self, tx_receipt: &TransactionReceipt) {
+        self.included += 1;
+        if tx_receipt.status == Some(U64::one()) {
+            self.gas_used += tx_receipt.gas_used.unwrap_or_default();
+        } else {
+            self.reverted += 1;
+        }
+    }
+
+    pub(crate) fn succeeded(&self) -> usize {
+        self.included - self.reverted
+    }
+
+    /// Avg. gas used by the succeeded txs.
+    pub(crate) fn avg_gas_used(&self) -> U256 {
+        self.gas_used / self.succeeded().max(1)
+    }
+}
+
+/// Inclusion of the tracked txs.
+#[derive(Debug, Default)]
+pub(crate) struct TrackerReport {
+    pub(crate) included: HashMap<TxHash, Inclusion>,
+    /// Txs flagged as not included within the timeout.
+    pub(crate) not_included: Vec<TxHash>,
+    /// No. of txs that failed to be sent, w/ the error of the first one.
+    pub(crate) failed: usize,
+    pub(crate) failure: Option<String>,
+    pub(crate) timeout: Duration,
+    /// Receipts of the included txs, fetched once so that the callers don't fetch them again.
+    pub(crate) receipts: HashMap<TxHash, TransactionReceipt>,
+    /// Effective gas prices of the included txs w/ a receipt, per tx type (EIP-2718).
+    pub(crate) gas_prices: BTreeMap<U64, GasPriceStats>,
+}
+
+impl TrackerReport {
+    /// Outcome of the included txs, grouped by the `group` of each tx.
+    pub(crate) fn receipt_stats_by<K: Ord>(
+        &self,
+        group: impl Fn(&TxHash) -> K,
+    ) -> BTreeMap<K, ReceiptStats> {
+        let mut stats: BTreeMap<K, ReceiptStats> = BTreeMap::new();
+        for (tx_hash, tx_receipt) in self.receipts.iter() {
+            stats.entry(group(tx_hash)).or_default().add(tx_receipt);
+        }
+
+        stats
+    }
+
+    /// Print the reverted txs, along w/ the no. of succeeded & reverted txs and the avg. gas used.
+    pub(crate) fn print_receipts(&self, contract_name: &str) {
+        let stats = self.receipt_stats_by(|_| ()).remove(&()).unwrap_or_default();
+        for (tx_hash, tx_receipt) in self.receipts.iter() {
+            if tx_receipt.status != Some(U64::one()) {
+                println!("\'{}\' tx reverted: {:?}", contract_name, tx_hash);
+            }
+        }
+        println!(
+            "\'{}\' txs: {} succeeded, {} reverted, avg. gas used: {}",
+            contract_name,
+            stats.succeeded(),
+            stats.reverted,
+            stats.avg_gas_used()
+        );
+    }
+
+    /// Print the no. of txs included per block, those flagged or failed to be sent, and the
+    /// effective gas price paid per tx type.
+    pub(crate) fn print(&self, contract_name: &str) {
+        let mut per_block: BTreeMap<U64, usize> = BTreeMap::new();
+        for inclusion in self.included.values() {
+            *per_block.entry(inclusion.block_number).or_default() += 1;
+        }
+        for (block_number, num_txs) in per_block.iter() {
+            println!("Block #{}: {} \'{}\' txs included", block_number, num_txs, contract_name);
+        }
+
+        println!(
+            "{} \'{}\' txs included, {} not included within {:?}, {} failed to be sent",
+            self.included.len(),
+            contract_name,
+            self.not_included.len(),
+            self.timeout,
+            self.failed
+        );
+        for tx_hash in self.not_included.iter() {
+            println!("Not included: {:?}", tx_hash);
+        }
+        if let Some(failure) = &self.failure {
+            println!("Failed to be sent, e.g.: {}", failure);
+        }
+        for (tx_type, stats) in self.gas_prices.iter() {
+            println!(
+                "Effective gas price of {} type-{} \'{}\' txs: min. {} Gwei, avg. {} Gwei, max. {} Gwei",
+                stats.txs,
+                tx_type,
+                contract_name,
+                wei_to_gwei_string(stats.min),
+                wei_to_gwei_string(stats.sum / stats.txs),
+                wei_to_gwei_string(stats.max)
+            );
+        }
+    }
+}
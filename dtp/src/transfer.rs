@@ -0,0 +1,159 @@
+use crate::{
+    fees::{signer_middleware, TxFees},
+    transport::Client,
+    utils::{run_tracked, wei_to_tssc_string, Senders},
+};
+use ethers::{core::k256::ecdsa::SigningKey, prelude::*, signers::Wallet};
+use futures::future::join_all;
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+/// Gas of a plain value transfer.
+pub(crate) const TRANSFER_GAS: u64 = 21_000;
+
+/// Recipients of the TRANSFER txs.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum TransferTo {
+    /// The generated signers send to each other (each to the next one).
+    Signers,
+    /// Each tx is sent to a random fresh address.
+    Fresh,
+}
+
+/// Implement `FromStr` trait for TransferTo
+impl FromStr for TransferTo {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "signers" => Ok(TransferTo::Signers),
+            "fresh" => Ok(TransferTo::Fresh),
+            _ => Err(format!("\'{}\' is not a valid TransferTo", s)),
+        }
+    }
+}
+
+/// Balances of the senders around the TRANSFER txs, for the end-of-run balance accounting.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TransferTotals {
+    /// Total balance of the senders before & after the txs.
+    pub(crate) senders_balance_before: U256,
+    pub(crate) senders_balance_after: U256,
+    /// Total value transferred by the included txs.
+    pub(crate) transferred: U256,
+    /// Part of `transferred` sent to fresh addresses i.e. out of the senders.
+    pub(crate) transferred_out: U256,
+}
+
+/// Get the total balance of `addresses`, fetched in chunks of `MAX_BATCH_SIZE`.
+async fn get_total_balance(
+    client: Arc<Client>,
+    addresses: &[Address],
+    max_batch_size: u16,
+) -> eyre::Result<U256> {
+    let mut total = U256::zero();
+    for chunk in addresses.chunks(max_batch_size.into()) {
+        let batch = chunk.iter().map(|address| client.get_balance(*address, None));
+        for balance in join_all(batch).await {
+            total += balance?;
+        }
+    }
+
+    Ok(total)
+}
+
+/// send a plain TSSC transfer of `amount` (in Wei) to `to` with the given `nonce` w/o waiting
+/// for its receipt
+/// NOTE: signer needed as it incurs gas fees.
+pub(crate) async fn transfer_send(
+    client: Arc<Client>,
+    signer: Wallet<SigningKey>,
+    chain_id: u64,
+    fees: &TxFees,
+    to: Address,
+    amount: U256,
+    nonce: U256,
+) -> eyre::Result<TxHash> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client, signer, chain_id, fees);
+
+    // the gas of a plain transfer is fixed, so not estimated
+    let tx = TransactionRequest::pay(to, amount).gas(TRANSFER_GAS).nonce(nonce);
+    let tx_hash = client_middleware.send_transaction(tx, None).await?.tx_hash();
+
+    Ok(tx_hash)
+}
+
+/// As TRANSFER transaction type, each new account sends `amount` (in Wei) of TSSC per tx,
+/// either to the next account or to a random fresh address (see `TransferTo`), in batches of
+/// `MAX_BATCH_SIZE`.
+///
+/// Returns the totals for the end-of-run balance accounting.
+pub(crate) async fn multicall_transfer_txs(
+    client: Arc<Client>,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    amount: u64,
+    transfer_to: TransferTo,
+    inclusion_timeout: Duration,
+) -> eyre::Result<TransferTotals> {
+    let amount = U256::from(amount);
+    let addresses: Vec<Address> = senders.signers.iter().map(|signer| signer.address()).collect();
+    let senders_balance_before =
+        get_total_balance(client.clone(), &addresses, max_batch_size).await?;
+
+    // check that the senders can afford all their transfers (incl. gas)
+    let gas_price = senders.fees.max_gas_price(client.as_ref()).await?;
+    let required_balance = (amount + gas_price * TRANSFER_GAS) * senders.num_txs();
+    eyre::ensure!(
+        senders_balance_before >= required_balance,
+        "Senders have {} TSSC in total, but their transfers need {} TSSC. Increase the funding amount.",
+        wei_to_tssc_string(senders_balance_before),
+        wei_to_tssc_string(required_balance)
+    );
+
+    // pick the recipient of each tx
+    let recipients: Vec<Address> = (0..senders.num_txs())
+        .map(|tx| match transfer_to {
+            TransferTo::Signers => addresses[(tx + 1) % addresses.len()],
+            TransferTo::Fresh => Address::random(),
+        })
+        .collect();
+
+    let txs: Vec<usize> = (0..senders.num_txs()).collect();
+    let (_, report) = run_tracked(
+        client.clone(),
+        &senders,
+        &txs,
+        max_batch_size,
+        inclusion_timeout,
+        "transfer",
+        |tx, signer, nonce| {
+            transfer_send(
+                client.clone(),
+                signer,
+                senders.chain_id,
+                &senders.fees,
+                recipients[tx],
+                amount,
+                nonce,
+            )
+        },
+    )
+    .await?;
+
+    let num_included = report.included.len();
+    let transferred = amount * num_included;
+    let transferred_out = match transfer_to {
+        TransferTo::Signers => U256::zero(),
+        TransferTo::Fresh => transferred,
+    };
+    let senders_balance_after =
+        get_total_balance(client.clone(), &addresses, max_batch_size).await?;
+
+    Ok(TransferTotals {
+        senders_balance_before,
+        senders_balance_after,
+        transferred,
+        transferred_out,
+    })
+}
@@ -0,0 +1,104 @@
+use crate::{
+    pool::{PoolStrategy, RpcPool},
+    retry::{RetryPolicy, RetryTransport},
+};
+use async_trait::async_trait;
+use ethers::prelude::*;
+use futures::channel::mpsc;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::value::RawValue;
+use std::fmt::Debug;
+
+/// Provider over a pool of HTTP and/or WebSocket endpoints (as picked at runtime), retrying
+/// transient RPC failures.
+pub(crate) type Client = Provider<RetryTransport>;
+
+/// JSON-RPC transport to the node, either HTTP or a single persistent WebSocket connection.
+///
+/// Only `Ws` supports subscriptions (e.g. `eth_subscribe("newHeads")`). So, block-driven logic
+/// polls the node over `Http`.
+#[derive(Debug, Clone)]
+pub(crate) enum Transport {
+    Http(Http),
+    Ws(Ws),
+}
+
+impl Transport {
+    /// Connect to an RPC node URL.
+    ///
+    /// A WebSocket transport is used if the URL scheme is `ws://`/`wss://`, or if `ws` is set, in
+    /// which case `http(s)://` is swapped for `ws(s)://`. Otherwise, HTTP is used.
+    pub(crate) async fn connect(rpc_url: &str, ws: bool) -> eyre::Result<Self> {
+        let transport = if rpc_url.starts_with("ws://") || rpc_url.starts_with("wss://") {
+            Transport::Ws(Ws::connect(rpc_url).await?)
+        } else if ws {
+            let ws_url = rpc_url.replacen("http", "ws", 1);
+            Transport::Ws(Ws::connect(ws_url).await?)
+        } else {
+            Transport::Http(rpc_url.parse::<Http>()?)
+        };
+
+        Ok(transport)
+    }
+
+    /// Whether the transport supports `eth_subscribe`.
+    pub(crate) fn is_pubsub(&self) -> bool {
+        matches!(self, Transport::Ws(_))
+    }
+}
+
+#[async_trait]
+impl JsonRpcClient for Transport {
+    type Error = ProviderError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        match self {
+            Transport::Http(http) => Ok(JsonRpcClient::request(http, method, params).await?),
+            Transport::Ws(ws) => Ok(JsonRpcClient::request(ws, method, params).await?),
+        }
+    }
+}
+
+impl PubsubClient for Transport {
+    type NotificationStream = mpsc::UnboundedReceiver<Box<RawValue>>;
+
+    fn subscribe<T: Into<U256>>(&self, id: T) -> Result<Self::NotificationStream, Self::Error> {
+        match self {
+            Transport::Http(_) => Err(ProviderError::CustomError(
+                "HTTP transport doesn't support subscriptions".into(),
+            )),
+            Transport::Ws(ws) => Ok(ws.subscribe(id)?),
+        }
+    }
+
+    fn unsubscribe<T: Into<U256>>(&self, id: T) -> Result<(), Self::Error> {
+        match self {
+            Transport::Http(_) => Err(ProviderError::CustomError(
+                "HTTP transport doesn't support subscriptions".into(),
+            )),
+            Transport::Ws(ws) => Ok(ws.unsubscribe(id)?),
+        }
+    }
+}
+
+/// Connect to the node RPC URLs (see `Transport::connect`) and pool them, picking an endpoint
+/// for each request as per `strategy`. Transient RPC failures are retried as per `retry_policy`.
+pub(crate) async fn connect(
+    rpc_urls: &[String],
+    ws: bool,
+    strategy: PoolStrategy,
+    retry_policy: RetryPolicy,
+) -> eyre::Result<Client> {
+    eyre::ensure!(!rpc_urls.is_empty(), "At least 1 RPC URL is required");
+
+    let mut endpoints = Vec::with_capacity(rpc_urls.len());
+    for rpc_url in rpc_urls {
+        endpoints.push((rpc_url.clone(), Transport::connect(rpc_url, ws).await?));
+    }
+
+    Ok(Provider::new(RetryTransport::new(RpcPool::new(endpoints, strategy), retry_policy)))
+}
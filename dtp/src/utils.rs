@@ -1,4 +1,18 @@
-use crate::contracts::{counter_get_number, counter_increment, load_set_array};
+use crate::{
+    contracts::{
+        counter_get_number, counter_increment_send, load_set_array_estimate_gas,
+        load_set_array_send,
+    },
+    fees::{signer_middleware, TxFees},
+    nonce::NonceManager,
+    scheduler::{
+        get_block_gas_limit, get_block_slices, pack_into_block_bins, report_sent_slots,
+        send_in_blocks,
+    },
+    tracker::{InclusionTracker, TrackerReport},
+    transfer::TransferTotals,
+    transport::Client,
+};
 use bindings::fund::Fund;
 use ethers::{
     core::k256::ecdsa::SigningKey,
@@ -8,18 +22,140 @@ use ethers::{
 };
 use futures::future::join_all;
 use log::info;
-use std::sync::Arc;
+use std::{cmp::Ordering, future::Future, sync::Arc, time::Duration};
+
+/// No. of recipients used to estimate the gas per recipient of `transferTsscToMany`.
+const FUNDING_GAS_SAMPLE_LEN: usize = 10;
+
+/// Margin (in %) added over the estimated gas of each `transferTsscToMany` call.
+const FUNDING_GAS_MARGIN_PERCENT: u64 = 10;
 
 /// Convert Wei to TSSC (in String)
 pub(crate) fn wei_to_tssc_string(bal_wei: U256) -> String {
-    let bal_tssc = format_units(bal_wei, "ether").unwrap();
-    bal_tssc
+    format_units(bal_wei, "ether").unwrap()
 }
 
 /// Convert Wei to TSSC (in f64)
 pub(crate) fn wei_to_tssc_f64(bal_wei: U256) -> f64 {
-    let bal_tssc = bal_wei.as_usize() as f64 / 1e18;
-    bal_tssc
+    bal_wei.as_usize() as f64 / 1e18
+}
+
+/// Generated accounts sending the workload txs, each sending `txs_per_account` txs with nonces
+/// handed out by a local `NonceManager`, w/ the envelope & `fees` of the run.
+pub(crate) struct Senders {
+    pub(crate) signers: Vec<Wallet<SigningKey>>,
+    pub(crate) chain_id: u64,
+    pub(crate) fees: TxFees,
+    pub(crate) txs_per_account: u32,
+    pub(crate) nonces: NonceManager,
+}
+
+impl Senders {
+    /// Fetch the starting nonce of each of the `signers` to send `txs_per_account` txs each.
+    pub(crate) async fn new(
+        client: Arc<Client>,
+        signers: Vec<Wallet<SigningKey>>,
+        chain_id: u64,
+        fees: TxFees,
+        txs_per_account: u32,
+        max_batch_size: u16,
+    ) -> eyre::Result<Self> {
+        eyre::ensure!(txs_per_account > 0, "Number of txs per account must be > 0");
+
+        let addresses: Vec<Address> = signers.iter().map(|signer| signer.address()).collect();
+        let nonces = NonceManager::new(client.as_ref(), &addresses, max_batch_size).await?;
+
+        Ok(Self { signers, chain_id, fees, txs_per_account, nonces })
+    }
+
+    /// Total no. of txs sent by all the accounts.
+    pub(crate) fn num_txs(&self) -> usize {
+        self.signers.len() * self.txs_per_account as usize
+    }
+
+    /// Signer of the `tx`-th tx. The txs go round-robin over the accounts, so that an account's
+    /// txs are spread across the run in nonce order.
+    pub(crate) fn signer(&self, tx: usize) -> &Wallet<SigningKey> {
+        &self.signers[tx % self.signers.len()]
+    }
+}
+
+/// Send the `txs` of the `senders` in batches of `MAX_BATCH_SIZE`, each via `send` w/ the tx, its
+/// signer & the signer's next nonce, fire-and-forget into the `tracker`.
+///
+/// A tx that fails to be sent is counted by the `tracker` (and its nonce handed back), rather than
+/// stopping the run. Returns the sent txs along w/ their hashes.
+pub(crate) async fn send_tracked<F, Fut>(
+    client: &Client,
+    senders: &Senders,
+    txs: &[usize],
+    max_batch_size: u16,
+    tracker: &InclusionTracker,
+    send: F,
+) -> Vec<(usize, TxHash)>
+where
+    F: Fn(usize, Wallet<SigningKey>, U256) -> Fut,
+    Fut: Future<Output = eyre::Result<TxHash>>,
+{
+    let mut sent = Vec::with_capacity(txs.len());
+    for chunk in txs.chunks(max_batch_size.into()) {
+        let batch = chunk.iter().map(|&tx| {
+            let signer = senders.signer(tx);
+            let send = &send;
+            senders
+                .nonces
+                .send(client, signer.address(), move |nonce| send(tx, signer.clone(), nonce))
+        });
+
+        for (&tx, res) in chunk.iter().zip(join_all(batch).await) {
+            match res {
+                Ok(tx_hash) => {
+                    tracker.track(tx_hash);
+                    sent.push((tx, tx_hash));
+                }
+                Err(err) => tracker.track_failure(&err),
+            }
+        }
+    }
+
+    sent
+}
+
+/// Start an `InclusionTracker`, have `send` send the txs into it, then wait until each of them is
+/// settled & print the report as `label` txs. Returns what `send` returned, along w/ the report.
+pub(crate) async fn track_inclusion<T>(
+    client: Arc<Client>,
+    inclusion_timeout: Duration,
+    label: &str,
+    send: impl AsyncFnOnce(&InclusionTracker) -> eyre::Result<T>,
+) -> eyre::Result<(T, TrackerReport)> {
+    let tracker = InclusionTracker::start(client, inclusion_timeout).await?;
+    let sent = send(&tracker).await?;
+    let report = tracker.finish().await?;
+    report.print(label);
+
+    Ok((sent, report))
+}
+
+/// Send the `txs` of the `senders` via `send_tracked`, tracked until each of them is settled, and
+/// print the report as `label` txs. Returns the sent txs along w/ their hashes, and the report.
+pub(crate) async fn run_tracked<F, Fut>(
+    client: Arc<Client>,
+    senders: &Senders,
+    txs: &[usize],
+    max_batch_size: u16,
+    inclusion_timeout: Duration,
+    label: &str,
+    send: F,
+) -> eyre::Result<(Vec<(usize, TxHash)>, TrackerReport)>
+where
+    F: Fn(usize, Wallet<SigningKey>, U256) -> Fut,
+    Fut: Future<Output = eyre::Result<TxHash>>,
+{
+    track_inclusion(client.clone(), inclusion_timeout, label, async |tracker| {
+        Ok(send_tracked(client.as_ref(), senders, txs, max_batch_size, tracker, send).await)
+    })
+    .await
 }
 
 /// Handle future calls by batching method into a batch of max. chunk size.
@@ -45,43 +181,45 @@ pub(crate) fn wei_to_tssc_f64(bal_wei: U256) -> f64 {
 /// Use `batch` to run each batch via `join_all(batch).await`. E.g. for 1000 connections,
 /// there would be 10 batches of 100 calls/requests each. Now, each batch i.e. 100 requests
 /// is sent at once, unlike all 1000 (total) calls sent at once as was done previously.
+///
+/// With `txs_per_account` > 1, an account's txs may share a batch, as their nonces are handed out
+/// locally w/o waiting for the previous receipts.
+///
+/// Sends are fire-and-forget into the `tracker`, so that a batch only waits for its txs to be sent
+/// (not confirmed) before the next batch starts.
 async fn handle_async_calls_in_batch_light(
-    client: Arc<Provider<Http>>,
+    client: Arc<Client>,
     counter_address: Address,
-    signers: Vec<Wallet<SigningKey>>,
-    chain_id: u64,
+    senders: Arc<Senders>,
     max_batch_size: u16,
+    tracker: &InclusionTracker,
 ) -> eyre::Result<()> {
-    // iteration in chunks of `MAX_BATCH_SIZE`
-    for chunk in signers.chunks(max_batch_size.into()) {
-        // create a batch vec for this chunk
-        let mut batch = Vec::with_capacity(chunk.len());
-
-        for signer in chunk {
-            batch.push(counter_increment(
-                client.clone(),
-                counter_address,
-                signer.to_owned(),
-                chain_id,
-            ));
-        }
-
-        // Send txs in a batch of `MAX_BATCH_SIZE`
-        // If any of the futures in this batch returns an error, it will stop and return that error
-        join_all(batch).await;
-        // handle errors
-    }
+    let txs: Vec<usize> = (0..senders.num_txs()).collect();
+    send_tracked(client.as_ref(), &senders, &txs, max_batch_size, tracker, |_, signer, nonce| {
+        counter_increment_send(
+            client.clone(),
+            counter_address,
+            signer,
+            senders.chain_id,
+            &senders.fees,
+            nonce,
+        )
+    })
+    .await;
 
     Ok(())
 }
 
 /// Approach-2: All new wallet accounts are sender for each call
+///
+/// The inclusion of the txs is tracked by scanning the new blocks, and the txs not included within
+/// `inclusion_timeout` of being sent are flagged.
 pub(crate) async fn multicall_light_txs_2(
-    client: Arc<Provider<Http>>,
+    client: Arc<Client>,
     counter_address: Address,
-    signers: Vec<Wallet<SigningKey>>,
-    chain_id: u64,
+    senders: Arc<Senders>,
     max_batch_size: u16,
+    inclusion_timeout: Duration,
 ) -> eyre::Result<()> {
     // get the number value before calls
     let num_before = counter_get_number(client.clone(), counter_address)
@@ -90,53 +228,213 @@ pub(crate) async fn multicall_light_txs_2(
     info!("Number stored in \'Counter\' before calls: {}\n", num_before);
 
     // Handle async calls in batches where each batch has `MAX_BATCH_SIZE` requests.
-    handle_async_calls_in_batch_light(
-        client.clone(),
-        counter_address,
-        signers.to_owned(),
-        chain_id,
-        max_batch_size,
-    )
+    track_inclusion(client.clone(), inclusion_timeout, "Counter::increment()", async |tracker| {
+        handle_async_calls_in_batch_light(
+            client.clone(),
+            counter_address,
+            senders.clone(),
+            max_batch_size,
+            tracker,
+        )
+        .await
+    })
     .await?;
 
     // get the number value before calls
     let num_after = counter_get_number(client.clone(), counter_address)
         .await
         .expect("Unable to get Counter number after calls.");
-    info!("Number stored in \'Counter\' after {} calls: {}\n", signers.clone().len(), num_after);
+    info!("Number stored in \'Counter\' after {} calls: {}\n", senders.num_txs(), num_after);
+
+    Ok(())
+}
+
+/// Wait for the receipt of a sent tx.
+pub(crate) async fn wait_for_receipt(
+    client: Arc<Client>,
+    tx_hash: TxHash,
+) -> eyre::Result<TransactionReceipt> {
+    let tx_receipt = PendingTransaction::new(tx_hash, client.as_ref())
+        .await?
+        .ok_or_else(|| eyre::eyre!("tx {:?} dropped from the mempool", tx_hash))?;
+
+    Ok(tx_receipt)
+}
+
+/// Like `multicall_light_txs_2`, but spreads the senders' `Counter::increment` calls across
+/// exactly `num_blocks` consecutive blocks.
+///
+/// Each slice (see `get_block_slices`) is sent (in chunks of `MAX_BATCH_SIZE`) right after a new
+/// block head is seen. At the end, the block(s) each slice actually landed in are reported.
+pub(crate) async fn multicall_light_txs_in_blocks(
+    client: Arc<Client>,
+    counter_address: Address,
+    senders: Arc<Senders>,
+    max_batch_size: u16,
+    num_blocks: u32,
+) -> eyre::Result<()> {
+    eyre::ensure!(num_blocks > 0, "Number of blocks must be > 0");
+
+    let num_txs = senders.num_txs();
+    match num_txs.cmp(&(num_blocks as usize)) {
+        Ordering::Less => println!(
+            "{} txs < {} blocks, so {} blocks will be left without any tx.",
+            num_txs,
+            num_blocks,
+            num_blocks as usize - num_txs
+        ),
+        Ordering::Equal => {
+            println!("{} txs = {} blocks, so 1 tx per block.", num_txs, num_blocks)
+        }
+        Ordering::Greater => println!(
+            "{} txs > {} blocks, so ~{} txs per block.",
+            num_txs,
+            num_blocks,
+            num_txs.div_ceil(num_blocks as usize)
+        ),
+    }
+
+    // get the number value before calls
+    let num_before = counter_get_number(client.clone(), counter_address)
+        .await
+        .expect("Unable to get Counter number before calls.");
+    info!("Number stored in \'Counter\' before calls: {}\n", num_before);
+
+    let slots = get_block_slices(num_txs, num_blocks as usize)
+        .into_iter()
+        .map(|slice| slice.collect())
+        .collect();
+    let sent_slots = send_in_blocks(client.clone(), slots, max_batch_size, |tx| {
+        let client = client.clone();
+        let senders = senders.clone();
+        let signer = senders.signer(tx).to_owned();
+        async move {
+            senders
+                .nonces
+                .send(client.as_ref(), signer.address(), |nonce| {
+                    counter_increment_send(
+                        client.clone(),
+                        counter_address,
+                        signer.clone(),
+                        senders.chain_id,
+                        &senders.fees,
+                        nonce,
+                    )
+                })
+                .await
+        }
+    })
+    .await?;
+    report_sent_slots(client.clone(), sent_slots, "Counter::increment()").await?;
+
+    // get the number value after calls
+    let num_after = counter_get_number(client.clone(), counter_address)
+        .await
+        .expect("Unable to get Counter number after calls.");
+    info!("Number stored in \'Counter\' after {} calls: {}\n", num_txs, num_after);
 
     Ok(())
 }
 
 /// Like `handle_async_calls_in_batch_light` but for HEAVY txs.
 /// Considered `Load` contract's `setArray` method as HEAVY txs.
+///
+/// As each `setArray(MAX_LOAD_COUNT_PER_BLOCK)` call is sized to roughly fill a block, the txs are
+/// not fired all at once. Instead, the estimated gas of each tx is packed into per-block bins
+/// w.r.t the chain's block gas limit, and each bin is released only once the previous block has
+/// been produced. With `num_blocks`, the bins are spread across exactly that many blocks.
 async fn handle_async_calls_in_batch_heavy(
-    client: Arc<Provider<Http>>,
+    client: Arc<Client>,
     load_address: Address,
-    signers: Vec<Wallet<SigningKey>>,
-    chain_id: u64,
+    senders: Arc<Senders>,
     max_batch_size: u16,
     max_load_count_per_block: u16,
+    num_blocks: Option<u32>,
 ) -> eyre::Result<()> {
-    // iteration in chunk of `MAX_BATCH_SIZE`
-    for chunk in signers.chunks(max_batch_size.into()) {
-        // create a batch vec for this chunk
-        let mut batch = Vec::with_capacity(chunk.len());
-
-        for signer in chunk {
-            batch.push(load_set_array(
+    // estimate the gas of each account's tx in chunks of `MAX_BATCH_SIZE`, each account paying
+    // up to the same max. gas price
+    let max_gas_price = senders.fees.max_gas_price(client.as_ref()).await?;
+    let mut estimated_gas = Vec::with_capacity(senders.signers.len());
+    for chunk in senders.signers.chunks(max_batch_size.into()) {
+        let batch = chunk.iter().map(|signer| {
+            load_set_array_estimate_gas(
                 client.clone(),
                 load_address,
                 signer.to_owned(),
-                chain_id,
+                senders.chain_id,
+                &senders.fees,
                 max_load_count_per_block,
-            ));
+                max_gas_price,
+            )
+        });
+        for gas in join_all(batch).await {
+            estimated_gas.push(gas?);
         }
-
-        // Send txs in a batch of `MAX_BATCH_SIZE`
-        // If any of the futures in this batch returns an error, it will stop and return that error
-        join_all(batch).await;
     }
+    // all the txs of an account have the same estimate
+    let num_txs = senders.num_txs();
+    let estimated_gas: Vec<U256> =
+        (0..num_txs).map(|tx| estimated_gas[tx % senders.signers.len()]).collect();
+
+    // pack the txs into per-block bins
+    let block_gas_limit = get_block_gas_limit(client.clone()).await?;
+    let bins = pack_into_block_bins(&estimated_gas, block_gas_limit)?;
+    println!(
+        "Packed {} heavy txs into {} blocks (block gas limit: {}).",
+        num_txs,
+        bins.len(),
+        block_gas_limit
+    );
+
+    // spread the bins across `num_blocks` blocks, if any
+    let slots = match num_blocks {
+        Some(num_blocks) => {
+            eyre::ensure!(num_blocks > 0, "Number of blocks must be > 0");
+            eyre::ensure!(
+                bins.len() <= num_blocks as usize,
+                "{} heavy txs need at least {} blocks, but only {} blocks are given",
+                num_txs,
+                bins.len(),
+                num_blocks
+            );
+            get_block_slices(bins.len(), num_blocks as usize)
+                .into_iter()
+                .map(|slice| bins[slice].concat())
+                .collect()
+        }
+        None => bins,
+    };
+
+    let sent_slots = send_in_blocks(client.clone(), slots, max_batch_size, |tx| {
+        let client = client.clone();
+        let senders = senders.clone();
+        let signer = senders.signer(tx).to_owned();
+        let gas = estimated_gas[tx];
+        async move {
+            senders
+                .nonces
+                .send(client.as_ref(), signer.address(), |nonce| {
+                    load_set_array_send(
+                        client.clone(),
+                        load_address,
+                        signer.clone(),
+                        senders.chain_id,
+                        &senders.fees,
+                        max_load_count_per_block,
+                        gas,
+                        nonce,
+                    )
+                })
+                .await
+        }
+    })
+    .await?;
+    report_sent_slots(
+        client,
+        sent_slots,
+        format!("Load::setArray({})", max_load_count_per_block).as_str(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -144,25 +442,25 @@ async fn handle_async_calls_in_batch_heavy(
 /// As HEAVY transaction type, multicall particular function of Load contract
 /// let's say `setArray` method successively done by each new accounts w/o
 /// `num_txs` cli arg. Here, instead of sending `calls` at once via `join_all(calls).await`,
-/// use `batch` to run each batch via `join_all(batch).await`. E.g. for 1000 connections,
-/// there would be 10 batches of 100 calls/requests each. Now, each batch i.e. 100 requests
-/// is sent at once, unlike all 1000 (total) calls sent at once as done similar for light txs.
+/// the txs are packed into blocks based on their estimated gas (see
+/// `handle_async_calls_in_batch_heavy`), and each block's txs are sent in batches of
+/// `MAX_BATCH_SIZE`.
 pub(crate) async fn multicall_heavy_txs_2(
-    client: Arc<Provider<Http>>,
+    client: Arc<Client>,
     load_address: Address,
-    signers: Vec<Wallet<SigningKey>>,
-    chain_id: u64,
+    senders: Arc<Senders>,
     max_batch_size: u16,
     max_load_count_per_block: u16,
+    num_blocks: Option<u32>,
 ) -> eyre::Result<()> {
-    // Handle async calls in batches where each batch has `MAX_BATCH_SIZE` requests.
+    // Handle async calls in per-block bins where each bin is sent in batches of `MAX_BATCH_SIZE`.
     handle_async_calls_in_batch_heavy(
         client.clone(),
         load_address,
-        signers.to_owned(),
-        chain_id,
+        senders,
         max_batch_size,
         max_load_count_per_block,
+        num_blocks,
     )
     .await?;
 
@@ -170,7 +468,9 @@ pub(crate) async fn multicall_heavy_txs_2(
 }
 
 /// Get contract addresses from env variables from `.env` file
-pub(crate) async fn get_env_vars() -> eyre::Result<(Address, Address, Address, Address, u16, u16)> {
+#[allow(clippy::type_complexity)]
+pub(crate) async fn get_env_vars(
+) -> eyre::Result<(Address, Address, Address, Address, Option<Address>, u16, u16)> {
     // get Counter contract address
     let counter_address =
         std::env::var("COUNTER").expect("Failed to get \'Counter\' contract address");
@@ -189,6 +489,14 @@ pub(crate) async fn get_env_vars() -> eyre::Result<(Address, Address, Address, A
     let fund_address = std::env::var("FUND").expect("Failed to get \'Fund\' contract address");
     let fund_address = fund_address.parse::<Address>()?;
 
+    // get ERC20 token contract address, if any (otherwise, deployed when needed)
+    let erc20_address = match std::env::var("ERC20") {
+        Ok(erc20_address) if !erc20_address.trim().is_empty() => {
+            Some(erc20_address.trim().parse::<Address>()?)
+        }
+        _ => None,
+    };
+
     // get max batch size
     let max_batch_size = std::env::var("MAX_BATCH_SIZE").expect("Failed to get \'max batch size\'");
     let max_batch_size = max_batch_size.parse::<u16>()?;
@@ -203,6 +511,7 @@ pub(crate) async fn get_env_vars() -> eyre::Result<(Address, Address, Address, A
         load_address,
         multicall_address,
         fund_address,
+        erc20_address,
         max_batch_size,
         max_load_count_per_block,
     ))
@@ -211,7 +520,7 @@ pub(crate) async fn get_env_vars() -> eyre::Result<(Address, Address, Address, A
 /// Get funder wallet after importing funder private key and also check for required funder balance
 /// in order to transfer the funds to the newly created accounts.
 pub(crate) async fn get_funder_wallet_and_check_required_balance(
-    client: Arc<Provider<Http>>,
+    client: Arc<Client>,
     initial_funded_account_private_key: String,
     funding_amount: u64,
     num_accounts: u32,
@@ -235,13 +544,10 @@ pub(crate) async fn get_funder_wallet_and_check_required_balance(
     );
 
     // check for sufficient balance in funder's account
-    assert!(
+    eyre::ensure!(
         funder_balance_wei_initial > required_balance,
-        "{}",
-        &format!(
-            "funder has insufficient balance by {:?}",
-            required_balance.checked_sub(funder_balance_wei_initial)
-        )
+        "funder has insufficient balance by {} Wei",
+        required_balance.saturating_sub(funder_balance_wei_initial)
     );
 
     Ok((funder_wallet, funder_address, funder_balance_wei_initial))
@@ -278,14 +584,24 @@ fn get_wallets_addresses(
 /// Generates a specified number of wallets, funds them by calling a contract's `transferTsscToMany` method,
 /// and returns the collection of generated wallets.
 ///
+/// As a single `transferTsscToMany` call with all the addresses exceeds the block gas limit for
+/// large account sets (1500+), the addresses are split into as many calls as needed, each staying
+/// under `funding_block_gas_share` % of the block gas limit. The calls are sent back-to-back with
+/// sequential funder nonces, and all of them are awaited before returning.
+///
 /// # Arguments
 ///
-/// * `client` - An `Arc` wrapped `Provider` for HTTP requests.
+/// * `client` - An `Arc` wrapped `Provider` for HTTP/WebSocket requests.
 /// * `num_accounts` - The number of wallets to generate.
+/// * `num_funded` - The number of wallets funded, out of the first ones (e.g. only the sender of
+///   the `aggregate3` txs).
 /// * `funder_wallet` - The wallet instance used to fund the new wallets.
 /// * `funding_amount` - The amount of funds to transfer to each wallet.
 /// * `fund_contract_addr` - The smart contract address used for transferring funds.
 /// * `chain_id` - The identifier of the specific Ethereum network chain being used.
+/// * `fees` - The envelope & fees of the funding txs.
+/// * `funding_block_gas_share` - The max. share (in %) of the block gas limit used by each funding
+///   call.
 ///
 /// # Returns
 ///
@@ -299,58 +615,188 @@ fn get_wallets_addresses(
 /// let wallets = gen_wallets_transfer_tssc(
 ///     client,
 ///     5,
+///     5,
 ///     funder_wallet,
 ///     1000,
 ///     fund_contract_addr,
-///     1
+///     1,
+///     &TxFees::default(),
+///     50
 /// ).await?;
 /// ```
 ///
 /// # Errors
 ///
 /// This function will return an error if the contract's method call to transfer funds fails.
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn gen_wallets_transfer_tssc(
-    client: Arc<Provider<Http>>,
+    client: Arc<Client>,
     num_accounts: u32,
+    num_funded: u32,
     funder_wallet: Wallet<SigningKey>,
     funding_amount: u64,
     fund_contract_addr: Address,
     chain_id: u64,
+    fees: &TxFees,
+    funding_block_gas_share: u8,
 ) -> eyre::Result<Vec<Wallet<SigningKey>>> {
+    eyre::ensure!(
+        (1..=100).contains(&funding_block_gas_share),
+        "Funding block gas share must be within 1-100 %"
+    );
+    eyre::ensure!(num_funded <= num_accounts, "Can't fund more than the {} accounts", num_accounts);
+
     // get wallets and addresses
-    let (wallets, addresses) =
+    let (wallets, mut addresses) =
         get_wallets_addresses(num_accounts).expect("Not able to generate wallets or addresses");
+    addresses.truncate(num_funded as usize);
+    if addresses.is_empty() {
+        return Ok(wallets);
+    }
+    let funding_amount = U256::from(funding_amount);
 
-    // Log the initiation of the bulk fund transfer operation
-    println!("\nInitiating bulk transfer via the 'Fund' contract's 'transferTsscToMany' method...");
-
-    // Perform the bulk transfer by invoking the contract's method
-    transfer_tssc_bulk(
-        client,
+    // estimate the gas per recipient (and the base gas of a call) from 1 & a few recipients
+    let sample_len = addresses.len().min(FUNDING_GAS_SAMPLE_LEN);
+    let gas_one = estimate_transfer_tssc_bulk(
+        client.clone(),
         &funder_wallet,
-        addresses,
-        U256::from(funding_amount),
+        addresses[..1].to_vec(),
+        funding_amount,
         fund_contract_addr,
         chain_id,
+        fees,
     )
     .await?;
+    let gas_per_recipient = if sample_len > 1 {
+        let gas_sample = estimate_transfer_tssc_bulk(
+            client.clone(),
+            &funder_wallet,
+            addresses[..sample_len].to_vec(),
+            funding_amount,
+            fund_contract_addr,
+            chain_id,
+            fees,
+        )
+        .await?;
+        gas_sample.saturating_sub(gas_one) / (sample_len - 1)
+    } else {
+        gas_one
+    };
+    let base_gas = gas_one.saturating_sub(gas_per_recipient);
+    // gas limit of a call w/ `len` recipients, with a margin over the estimate
+    let call_gas = |len: usize| {
+        (base_gas + gas_per_recipient * len) * (100 + FUNDING_GAS_MARGIN_PERCENT) / 100_u64
+    };
+
+    // max. no. of recipients per call, within the share of the block gas limit
+    let block_gas_limit = get_block_gas_limit(client.clone()).await?;
+    let gas_budget = block_gas_limit * funding_block_gas_share / 100_u64;
+    let max_recipients = (gas_budget * 100_u64 / (100 + FUNDING_GAS_MARGIN_PERCENT))
+        .saturating_sub(base_gas)
+        / gas_per_recipient.max(U256::one());
+    let chunk_size = max_recipients.min(U256::from(addresses.len())).as_usize().max(1);
+    let chunks: Vec<&[Address]> = addresses.chunks(chunk_size).collect();
+
+    // Log the initiation of the bulk fund transfer operation
+    println!(
+        "\nInitiating bulk transfer via the 'Fund' contract's 'transferTsscToMany' method in {} call(s) of up to {} recipients (~{} gas per recipient, {} gas budget per call)...",
+        chunks.len(),
+        chunk_size,
+        gas_per_recipient,
+        gas_budget
+    );
+
+    // Send the calls back-to-back with sequential funder nonces, w/o waiting for each receipt
+    let nonce = client
+        .get_transaction_count(funder_wallet.address(), Some(BlockNumber::Pending.into()))
+        .await?;
+    let mut tx_hashes = Vec::with_capacity(chunks.len());
+    for (i, chunk) in chunks.into_iter().enumerate() {
+        tx_hashes.push(
+            transfer_tssc_bulk(
+                client.clone(),
+                &funder_wallet,
+                chunk.to_vec(),
+                funding_amount,
+                fund_contract_addr,
+                chain_id,
+                fees,
+                nonce + i,
+                call_gas(chunk.len()),
+            )
+            .await?,
+        );
+    }
+
+    // Wait for every call before the workload starts
+    for tx_receipt in
+        join_all(tx_hashes.into_iter().map(|tx_hash| wait_for_receipt(client.clone(), tx_hash)))
+            .await
+    {
+        let tx_receipt = tx_receipt?;
+        eyre::ensure!(
+            tx_receipt.status == Some(U64::one()),
+            "Failure in \'transferTsscToMany\' function of Fund contract, tx hash: {:?}",
+            tx_receipt.transaction_hash
+        );
+        println!(
+            "\n\'{}\' sent funds to newly created accounts, which incurred a gas of \'{}\', has a tx hash: \'{:?}\', indexed at #{} in block #{}.\n",
+            tx_receipt.from,
+            tx_receipt.gas_used.unwrap_or_default(),
+            tx_receipt.transaction_hash,
+            tx_receipt.transaction_index,
+            tx_receipt.block_number.unwrap()
+        );
+    }
 
     // Return the wallets after funding
     Ok(wallets)
 }
 
-/// Transfer TSSC in bulk
+/// Estimate gas of transferring TSSC in bulk
+pub(crate) async fn estimate_transfer_tssc_bulk(
+    client: Arc<Client>,
+    from_wallet: &Wallet<SigningKey>,
+    tos: Vec<Address>,
+    funding_amount: U256,
+    fund_contract_addr: Address,
+    chain_id: u64,
+    fees: &TxFees,
+) -> eyre::Result<U256> {
+    // create a middleware client with signature from signer & provider
+    let client_middleware = signer_middleware(client.clone(), from_wallet.clone(), chain_id, fees);
+
+    // get a contract
+    let fund_contract = Fund::new(fund_contract_addr, Arc::new(client_middleware));
+
+    let estimated_gas = fund_contract
+        .transfer_tssc_to_many(tos.clone())
+        .value(
+            funding_amount
+                .checked_mul(U256::from(tos.len()))
+                .expect("Error in multiplying fund amount w receivers len."),
+        )
+        .estimate_gas()
+        .await?;
+
+    Ok(estimated_gas)
+}
+
+/// Transfer TSSC in bulk with the given funder `nonce` & `gas` limit w/o waiting for its receipt
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn transfer_tssc_bulk(
-    client: Arc<Provider<Http>>,
+    client: Arc<Client>,
     from_wallet: &Wallet<SigningKey>,
     tos: Vec<Address>,
     funding_amount: U256,
     fund_contract_addr: Address,
     chain_id: u64,
-) -> eyre::Result<()> {
+    fees: &TxFees,
+    nonce: U256,
+    gas: U256,
+) -> eyre::Result<TxHash> {
     // create a middleware client with signature from signer & provider
-    let client_middleware =
-        SignerMiddleware::new(client.clone(), from_wallet.clone().with_chain_id(chain_id));
+    let client_middleware = signer_middleware(client.clone(), from_wallet.clone(), chain_id, fees);
 
     // clone the client (if multiple use)
     let client_middleware = Arc::new(client_middleware);
@@ -358,36 +804,30 @@ pub(crate) async fn transfer_tssc_bulk(
     // get a contract
     let fund_contract = Fund::new(fund_contract_addr, client_middleware);
 
+    let value = funding_amount
+        .checked_mul(U256::from(tos.len()))
+        .ok_or_else(|| eyre::eyre!("Overflow in multiplying fund amount w receivers len."))?;
+
     // send a transaction with setter function
-    let tx_receipt = fund_contract
-        .transfer_tssc_to_many(tos.clone())
-        .value(
-            funding_amount
-                .checked_mul(U256::from(tos.clone().len()))
-                .expect("Error in multiplying fund amount w receivers len."),
-        )
+    let tx_hash = fund_contract
+        .transfer_tssc_to_many(tos)
+        .value(value)
+        .nonce(nonce)
+        .gas(gas)
         .send()
-        .await
-        .expect("Failure in getting pending tx")
         .await?
-        .expect("Failure in \'transferTsscToMany\' function of Fund contract");
-    println!(
-        "\n\'{}\' sent funds to newly created accounts, which incurred a gas of \'{}\', has a tx hash: \'{:?}\', indexed at #{} in block #{}.\n",
-        tx_receipt.from,
-        tx_receipt.gas_used.unwrap_or_default(),
-        tx_receipt.transaction_hash,
-        tx_receipt.transaction_index,
-        tx_receipt.block_number.unwrap()
-    );
+        .tx_hash();
 
-    Ok(())
+    Ok(tx_hash)
 }
 
-/// Show the funder's final balance at the end
+/// Show the funder's final balance at the end, along with the senders' balance accounting of the
+/// TRANSFER txs (if any).
 pub(crate) async fn show_funder_final_balance(
-    client: Arc<Provider<Http>>,
+    client: Arc<Client>,
     funder_address: Address,
     funder_balance_wei_initial: U256,
+    transfer_totals: Option<TransferTotals>,
 ) -> eyre::Result<()> {
     let funder_balance_wei_final = client.get_balance(funder_address, None).await?;
     let funder_balance_tssc_final = wei_to_tssc_string(funder_balance_wei_final);
@@ -397,5 +837,21 @@ pub(crate) async fn show_funder_final_balance(
     );
     println!("Funder spent: {:.18} TSSC", spent_bal_tssc);
 
+    if let Some(totals) = transfer_totals {
+        let senders_spent =
+            totals.senders_balance_before.saturating_sub(totals.senders_balance_after);
+        println!(
+            "Senders transferred: {} TSSC ({} TSSC to fresh addresses)",
+            wei_to_tssc_string(totals.transferred),
+            wei_to_tssc_string(totals.transferred_out)
+        );
+        println!(
+            "Senders spent: {} TSSC ({} TSSC transferred out, {} TSSC in gas fees)",
+            wei_to_tssc_string(senders_spent),
+            wei_to_tssc_string(totals.transferred_out),
+            wei_to_tssc_string(senders_spent.saturating_sub(totals.transferred_out))
+        );
+    }
+
     Ok(())
 }